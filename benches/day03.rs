@@ -0,0 +1,106 @@
+// Compares day 3 on the real input against the HashSet<char> version it replaced.
+// Run with `cargo bench --bench day03`.
+
+use std::{collections::HashSet, hint::black_box, time::Duration};
+
+use aoc2022::{
+    days::day03,
+    timing::{timed, Summary},
+};
+
+const INPUT: &str = include_str!("../inputs/03.txt");
+const RUNS: usize = 200;
+
+mod hashset {
+    use std::collections::HashSet;
+
+    use aoc2022::days::day03::priority;
+
+    pub struct Rucksack {
+        a: HashSet<char>,
+        b: HashSet<char>,
+    }
+
+    pub fn parse(input: &str) -> Vec<Rucksack> {
+        input
+            .lines()
+            .map(|l| {
+                let (a, b) = l.split_at(l.len() / 2);
+                Rucksack {
+                    a: a.chars().collect(),
+                    b: b.chars().collect(),
+                }
+            })
+            .collect()
+    }
+
+    pub fn part1(rucksacks: &[Rucksack]) -> u32 {
+        rucksacks
+            .iter()
+            .map(|r| priority(*r.a.intersection(&r.b).next().unwrap()).unwrap())
+            .sum()
+    }
+
+    pub fn part2(rucksacks: &[Rucksack]) -> u32 {
+        rucksacks
+            .chunks(3)
+            .map(|g| {
+                let common = g.iter().fold(super::all(), |h, r| {
+                    let all = r.a.union(&r.b).cloned().collect::<HashSet<char>>();
+                    h.intersection(&all).cloned().collect()
+                });
+                priority(*common.iter().next().unwrap()).unwrap()
+            })
+            .sum()
+    }
+}
+
+fn all() -> HashSet<char> {
+    ('a'..='z').chain('A'..='Z').collect()
+}
+
+fn measure<T>(f: impl Fn() -> T) -> Summary {
+    let samples = (0..RUNS)
+        .map(|_| timed(|| black_box(f())).1)
+        .collect::<Vec<Duration>>();
+    Summary::of(&samples).unwrap()
+}
+
+fn main() {
+    let old = hashset::parse(INPUT);
+    let new = day03::parse(INPUT).unwrap();
+    assert_eq!(hashset::part1(&old), day03::part1(&new).unwrap());
+    assert_eq!(hashset::part2(&old), day03::part2(&new).unwrap());
+
+    let stages = [
+        (
+            "parse",
+            measure(|| hashset::parse(INPUT).len()),
+            measure(|| day03::parse(INPUT).unwrap().len()),
+        ),
+        (
+            "part1",
+            measure(|| hashset::part1(&old)),
+            measure(|| day03::part1(&new).unwrap()),
+        ),
+        (
+            "part2",
+            measure(|| hashset::part2(&old)),
+            measure(|| day03::part2(&new).unwrap()),
+        ),
+    ];
+
+    println!(
+        "{:<6} {:>12} {:>12} {:>8}",
+        "stage", "hashset", "bitset", "speedup"
+    );
+    for (stage, old, new) in stages {
+        println!(
+            "{:<6} {:>12} {:>12} {:>7.1}x",
+            stage,
+            format!("{:.1?}", old.median),
+            format!("{:.1?}", new.median),
+            old.median.as_secs_f64() / new.median.as_secs_f64()
+        );
+    }
+}
@@ -0,0 +1,103 @@
+use std::fmt::Display;
+
+use anyhow::{anyhow, Error, Result};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(n: i64) -> Self {
+        Answer::Number(n)
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(n: i32) -> Self {
+        Answer::Number(n as i64)
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(n: u32) -> Self {
+        Answer::Number(n as i64)
+    }
+}
+
+// Unsigned answers don't always fit, and a wrapped number would be a wrong answer
+impl TryFrom<u64> for Answer {
+    type Error = Error;
+
+    fn try_from(n: u64) -> Result<Self> {
+        i64::try_from(n)
+            .map(Answer::Number)
+            .map_err(|_| anyhow!("answer {n} is too large"))
+    }
+}
+
+impl TryFrom<usize> for Answer {
+    type Error = Error;
+
+    fn try_from(n: usize) -> Result<Self> {
+        Answer::try_from(n as u64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answers {
+    pub part1: Answer,
+    pub part2: Answer,
+}
+
+impl Answers {
+    pub fn new(part1: impl IntoAnswer, part2: impl IntoAnswer) -> Result<Self> {
+        Ok(Answers {
+            part1: part1.into_answer()?,
+            part2: part2.into_answer()?,
+        })
+    }
+}
+
+// Converts the return value of a day's `part1`/`part2` into an `Answer`,
+// whether the part can fail or not.
+pub trait IntoAnswer {
+    fn into_answer(self) -> Result<Answer>;
+}
+
+macro_rules! impl_into_answer {
+    ($($t:ty),* => $convert:expr) => {
+        $(
+            impl IntoAnswer for $t {
+                fn into_answer(self) -> Result<Answer> {
+                    $convert(self)
+                }
+            }
+
+            impl IntoAnswer for Result<$t> {
+                fn into_answer(self) -> Result<Answer> {
+                    self.and_then(IntoAnswer::into_answer)
+                }
+            }
+        )*
+    };
+}
+
+impl_into_answer!(i32, i64, u32, String => |n| Ok(Answer::from(n)));
+impl_into_answer!(u64, usize => Answer::try_from);
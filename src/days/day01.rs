@@ -0,0 +1,305 @@
+use std::{cmp::Reverse, collections::BinaryHeap, fmt::Write, io::BufRead};
+
+use anyhow::Result;
+
+use crate::{answer::Answers, error::ParseError};
+
+const DAY: u8 = 1;
+
+pub fn parse(input: &str) -> Result<Vec<u64>> {
+    elves(input.as_bytes())
+        .map(|elf| elf.map(|elf| elf.total))
+        .collect()
+}
+
+pub fn part1(cals: &[u64]) -> u64 {
+    cals.iter().cloned().max().unwrap_or(0)
+}
+
+pub fn part2(cals: &[u64]) -> u64 {
+    let mut top = TopK::new(3);
+    for cal in cals {
+        top.push(*cal);
+    }
+    top.into_sorted_vec().iter().sum()
+}
+
+pub fn solve(input: &str) -> Result<Answers> {
+    let cals = parse(input)?;
+    Answers::new(part1(&cals), part2(&cals))
+}
+
+// The `k` largest elf totals in `reader`, largest first. Only the current elf
+// and the best `k` totals are kept in memory.
+pub fn top_k(reader: impl BufRead, k: usize) -> Result<Vec<u64>> {
+    let mut top = TopK::new(k);
+    for elf in elves(reader) {
+        top.push(elf?.total);
+    }
+    Ok(top.into_sorted_vec())
+}
+
+pub fn elves<R: BufRead>(reader: R) -> Elves<R> {
+    Elves {
+        reader,
+        line: String::new(),
+        line_no: 0,
+        count: 0,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Elf {
+    // 1-based, in input order
+    pub index: usize,
+    pub items: usize,
+    pub total: u64,
+    pub min: u64,
+    pub max: u64,
+}
+
+// Yields each elf, reading one block of lines at a time.
+pub struct Elves<R> {
+    reader: R,
+    line: String,
+    line_no: usize,
+    count: usize,
+}
+
+impl<R: BufRead> Elves<R> {
+    fn finish(&mut self, elf: Option<Elf>) -> Option<Result<Elf>> {
+        let mut elf = elf?;
+        self.count += 1;
+        elf.index = self.count;
+        Some(Ok(elf))
+    }
+
+    // Points at the line just read
+    fn error(&self, message: &str) -> Option<Result<Elf>> {
+        let text = self.line.trim_end_matches(['\n', '\r']);
+        Some(Err(ParseError {
+            day: DAY,
+            line: self.line_no,
+            column: 1,
+            text: text.to_string(),
+            message: message.to_string(),
+        }
+        .into()))
+    }
+}
+
+impl<R: BufRead> Iterator for Elves<R> {
+    type Item = Result<Elf>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut elf: Option<Elf> = None;
+
+        loop {
+            self.line.clear();
+            match self.reader.read_line(&mut self.line) {
+                Ok(0) => return self.finish(elf),
+                Ok(_) => self.line_no += 1,
+                Err(e) => return Some(Err(e.into())),
+            }
+
+            let line = self.line.trim_end_matches(['\n', '\r']);
+            if line.is_empty() {
+                if elf.is_some() {
+                    return self.finish(elf);
+                }
+                continue;
+            }
+
+            let Ok(cal) = line.parse::<u64>() else {
+                return self.error("invalid calories");
+            };
+            let elf = elf.get_or_insert(Elf {
+                index: 0,
+                items: 0,
+                total: 0,
+                min: cal,
+                max: cal,
+            });
+            let Some(total) = elf.total.checked_add(cal) else {
+                return self.error("total calories are too large");
+            };
+            elf.items += 1;
+            elf.total = total;
+            elf.min = elf.min.min(cal);
+            elf.max = elf.max.max(cal);
+        }
+    }
+}
+
+// Keeps the `k` largest values pushed into it in a min-heap, so the smallest
+// of them can be evicted when a larger one comes along.
+pub struct TopK {
+    k: usize,
+    heap: BinaryHeap<Reverse<u64>>,
+}
+
+impl TopK {
+    pub fn new(k: usize) -> Self {
+        TopK {
+            k,
+            // Grows with what is pushed, `k` can be far more than there are elves
+            heap: BinaryHeap::new(),
+        }
+    }
+
+    pub fn push(&mut self, value: u64) {
+        if self.heap.len() < self.k {
+            self.heap.push(Reverse(value));
+        } else if self.heap.peek().is_some_and(|min| min.0 < value) {
+            self.heap.pop();
+            self.heap.push(Reverse(value));
+        }
+    }
+
+    // Largest first
+    pub fn into_sorted_vec(self) -> Vec<u64> {
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|r| r.0)
+            .collect()
+    }
+}
+
+pub fn report(input: &str) -> Result<Report> {
+    Ok(Report {
+        elves: elves(input.as_bytes()).collect::<Result<_>>()?,
+    })
+}
+
+pub struct Report {
+    pub elves: Vec<Elf>,
+}
+
+impl Report {
+    fn sorted_totals(&self) -> Vec<u64> {
+        let mut totals = self.elves.iter().map(|e| e.total).collect::<Vec<_>>();
+        totals.sort_unstable();
+        totals
+    }
+
+    pub fn mean(&self) -> Option<f64> {
+        if self.elves.is_empty() {
+            return None;
+        }
+        // Every total fits in a u64 but their sum may not
+        let sum = self.elves.iter().map(|e| e.total as u128).sum::<u128>();
+        Some(sum as f64 / self.elves.len() as f64)
+    }
+
+    pub fn median(&self) -> Option<f64> {
+        let totals = self.sorted_totals();
+        let mid = totals.len() / 2;
+        match totals.len() {
+            0 => None,
+            n if n % 2 == 1 => Some(totals[mid] as f64),
+            // Halfway between the two middle totals, without adding them up
+            _ => {
+                let (a, b) = (totals[mid - 1], totals[mid]);
+                Some(a as f64 + (b - a) as f64 / 2.0)
+            }
+        }
+    }
+
+    // Nearest-rank percentile of the elf totals, `p` between 0 and 100
+    pub fn percentile(&self, p: u8) -> Option<u64> {
+        let totals = self.sorted_totals();
+        if totals.is_empty() {
+            return None;
+        }
+        let rank = (p.min(100) as usize * totals.len()).div_ceil(100);
+        Some(totals[rank.max(1) - 1])
+    }
+
+    // One line per bucket of totals, with a bar of at most `width` characters
+    pub fn histogram(&self, buckets: usize, width: usize) -> String {
+        let totals = self.sorted_totals();
+        let (Some(min), Some(max)) = (totals.first(), totals.last()) else {
+            return String::new();
+        };
+        // ceil((max - min + 1) / buckets) without overflowing on the full u64 range,
+        // and no more buckets than it takes to reach `max`
+        let size = (max - min) / buckets.max(1) as u64 + 1;
+        let buckets = (max - min) / size + 1;
+
+        let mut counts = vec![0; buckets as usize];
+        for total in totals.iter() {
+            counts[((total - min) / size) as usize] += 1;
+        }
+        let most = counts.iter().cloned().max().unwrap_or(0).max(1);
+
+        let mut out = String::new();
+        for (i, count) in counts.iter().enumerate() {
+            let low = min + i as u64 * size;
+            let bar = "#".repeat(count * width / most);
+            let high = low.saturating_add(size - 1).min(*max);
+            let line = format!("{:>7}-{:<7} {:>5} {bar}", low, high, count);
+            let _ = writeln!(out, "{}", line.trim_end());
+        }
+        out
+    }
+
+    pub fn table(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            "{:>5} {:>5} {:>8} {:>6} {:>6}",
+            "elf", "items", "total", "min", "max"
+        );
+        for elf in self.elves.iter() {
+            let _ = writeln!(
+                out,
+                "{:>5} {:>5} {:>8} {:>6} {:>6}",
+                elf.index, elf.items, elf.total, elf.min, elf.max
+            );
+        }
+
+        let _ = writeln!(out);
+        for (stat, value) in self.summary() {
+            let _ = writeln!(out, "{:<7} {value}", format!("{stat}:"));
+        }
+
+        let _ = writeln!(out);
+        out.push_str(&self.histogram(10, 40));
+        out
+    }
+
+    // Per-elf rows, then the summary as a second section. The histogram is
+    // only in `table`.
+    pub fn csv(&self) -> String {
+        let mut out = String::from("elf,items,total,min,max\n");
+        for elf in self.elves.iter() {
+            let _ = writeln!(
+                out,
+                "{},{},{},{},{}",
+                elf.index, elf.items, elf.total, elf.min, elf.max
+            );
+        }
+
+        let _ = writeln!(out, "\nstat,value");
+        for (stat, value) in self.summary() {
+            let _ = writeln!(out, "{stat},{value}");
+        }
+        out
+    }
+
+    // Mean, median and percentiles are left out when there are no elves
+    fn summary(&self) -> Vec<(String, String)> {
+        let mut stats = vec![("elves".to_string(), self.elves.len().to_string())];
+        if let (Some(mean), Some(median)) = (self.mean(), self.median()) {
+            stats.push(("mean".to_string(), format!("{mean:.1}")));
+            stats.push(("median".to_string(), format!("{median:.1}")));
+        }
+        for p in [10, 25, 75, 90, 99] {
+            if let Some(value) = self.percentile(p) {
+                stats.push((format!("p{p}"), value.to_string()));
+            }
+        }
+        stats
+    }
+}
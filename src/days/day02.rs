@@ -0,0 +1,149 @@
+use std::fmt::Write;
+
+use anyhow::{anyhow, Result};
+
+use crate::answer::Answers;
+
+use self::game::{Game, Round};
+
+pub mod analysis;
+pub mod game;
+pub mod tournament;
+
+const DAY: u8 = 2;
+
+// The strategy guide read with the puzzle's rules, scored through `Game`
+pub fn parse(input: &str) -> Result<Vec<Match>> {
+    Game::standard()
+        .parse_rounds(input)?
+        .iter()
+        .map(|round| Match::from_round(round).ok_or_else(|| anyhow!("missing outcome letter")))
+        .collect()
+}
+
+pub fn part1(matches: &[Match]) -> usize {
+    Game::standard().total(&rounds(matches))
+}
+
+pub fn part2(matches: &[Match]) -> usize {
+    Game::standard()
+        .fixed_total(&rounds(matches))
+        .expect("every outcome can be reached in the standard game")
+}
+
+pub fn solve(input: &str) -> Result<Answers> {
+    let matches = parse(input)?;
+    Answers::new(part1(&matches), part2(&matches))
+}
+
+fn rounds(matches: &[Match]) -> Vec<Round> {
+    matches.iter().map(Match::round).collect()
+}
+
+// Scores the strategy guide under a rule set, `rules` names it in the output
+pub fn report(input: &str, rules: &str, game: &Game) -> Result<Report> {
+    let rounds = game.parse_rounds(input)?;
+    Ok(Report {
+        rules: rules.to_string(),
+        weapons: game.weapons.iter().map(|w| w.name.clone()).collect(),
+        total: game.total(&rounds),
+        fixed_total: game.fixed_total(&rounds),
+    })
+}
+
+pub struct Report {
+    pub rules: String,
+    pub weapons: Vec<String>,
+    pub total: usize,
+    // None when some round asks for an outcome no weapon gives
+    pub fixed_total: Option<usize>,
+}
+
+impl Report {
+    pub fn table(&self) -> String {
+        let fixed_total = self
+            .fixed_total
+            .map(|t| t.to_string())
+            .unwrap_or_else(|| "n/a".to_string());
+        format!(
+            "Rules: {} ({})\nPart 1: {}\nPart 2: {fixed_total}\n",
+            self.rules,
+            self.weapons.join(", "),
+            self.total
+        )
+    }
+
+    pub fn csv(&self) -> String {
+        let mut out = String::from("rules,part1,part2\n");
+        let fixed_total = self.fixed_total.map(|t| t.to_string()).unwrap_or_default();
+        let _ = writeln!(out, "{},{},{fixed_total}", self.rules, self.total);
+        out
+    }
+}
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RPS {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl RPS {
+    // In the same order as the weapons of `Game::standard`
+    pub const ALL: [RPS; 3] = [RPS::Rock, RPS::Paper, RPS::Scissors];
+
+    // Each weapon beats the one before it in `ALL`, wrapping around
+    pub fn play(&self, other: &Self) -> MatchResult {
+        match (self.index() + 3 - other.index()) % 3 {
+            0 => MatchResult::Draw,
+            1 => MatchResult::Win,
+            _ => MatchResult::Loss,
+        }
+    }
+
+    pub fn index(&self) -> usize {
+        *self as usize
+    }
+}
+
+pub struct Match {
+    pub them: RPS,
+    pub our_result: MatchResult,
+    pub us: RPS,
+}
+
+impl Match {
+    // The same line as `Game::standard` reads it
+    pub fn round(&self) -> Round {
+        Round {
+            them: self.them.index(),
+            us: self.us.index(),
+            our_result: Some(self.our_result),
+        }
+    }
+
+    pub fn from_round(round: &Round) -> Option<Self> {
+        Some(Match {
+            them: *RPS::ALL.get(round.them)?,
+            our_result: round.our_result?,
+            us: *RPS::ALL.get(round.us)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatchResult {
+    Win,
+    Loss,
+    Draw,
+}
+
+impl MatchResult {
+    pub fn required_for(&self, them: &RPS) -> RPS {
+        RPS::ALL
+            .into_iter()
+            .find(|us| us.play(them) == *self)
+            .unwrap_or(*them)
+    }
+}
@@ -0,0 +1,131 @@
+use std::fmt::{Debug, Write};
+
+use super::{game::Game, Match, MatchResult, RPS};
+
+// Every order of three items
+const PERMUTATIONS: [[usize; 3]; 6] = [
+    [0, 1, 2],
+    [0, 2, 1],
+    [1, 0, 2],
+    [1, 2, 0],
+    [2, 0, 1],
+    [2, 1, 0],
+];
+
+// What X, Y and Z stand for, and the guide's total score under that reading
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Interpretation<T> {
+    pub mapping: [T; 3],
+    pub total: usize,
+}
+
+// Scores the guide under every reading of X/Y/Z, both as weapons we play
+// (part 1) and as outcomes we need (part 2).
+pub struct Analysis {
+    pub weapons: Vec<Interpretation<RPS>>,
+    pub outcomes: Vec<Interpretation<MatchResult>>,
+}
+
+pub fn analyze(matches: &[Match]) -> Analysis {
+    // The parsed guide reads X/Y/Z as Rock/Paper/Scissors, which gives back the letter
+    let letter = |m: &Match| m.us.index();
+    let game = Game::standard();
+
+    let weapons = interpretations(RPS::ALL, |mapping| {
+        matches
+            .iter()
+            .map(|m| {
+                let us = mapping[letter(m)];
+                game.play_score(us.index(), m.them.index())
+            })
+            .sum()
+    });
+
+    let outcomes = interpretations(
+        [MatchResult::Loss, MatchResult::Draw, MatchResult::Win],
+        |mapping| {
+            matches
+                .iter()
+                .map(|m| {
+                    let us = mapping[letter(m)].required_for(&m.them);
+                    game.play_score(us.index(), m.them.index())
+                })
+                .sum()
+        },
+    );
+
+    Analysis { weapons, outcomes }
+}
+
+fn interpretations<T: Copy>(
+    items: [T; 3],
+    total: impl Fn(&[T; 3]) -> usize,
+) -> Vec<Interpretation<T>> {
+    PERMUTATIONS
+        .iter()
+        .map(|p| {
+            let mapping = p.map(|i| items[i]);
+            Interpretation {
+                total: total(&mapping),
+                mapping,
+            }
+        })
+        .collect()
+}
+
+// The first interpretations with the highest and the lowest totals
+pub fn best_and_worst<T>(
+    all: &[Interpretation<T>],
+) -> Option<(&Interpretation<T>, &Interpretation<T>)> {
+    let best = all.iter().rev().max_by_key(|i| i.total)?;
+    let worst = all.iter().min_by_key(|i| i.total)?;
+    Some((best, worst))
+}
+
+impl Analysis {
+    pub fn table(&self) -> String {
+        let mut out = String::new();
+        section(&mut out, "X/Y/Z as weapons (part 1)", &self.weapons);
+        let _ = writeln!(out);
+        section(&mut out, "X/Y/Z as outcomes (part 2)", &self.outcomes);
+        out
+    }
+
+    pub fn csv(&self) -> String {
+        let mut out = String::from("part,x,y,z,total,mark\n");
+        csv_rows(&mut out, 1, &self.weapons);
+        csv_rows(&mut out, 2, &self.outcomes);
+        out
+    }
+}
+
+fn mark<T>(all: &[Interpretation<T>], i: &Interpretation<T>) -> &'static str {
+    match best_and_worst(all) {
+        Some((best, _)) if std::ptr::eq(best, i) => "best",
+        Some((_, worst)) if std::ptr::eq(worst, i) => "worst",
+        _ => "",
+    }
+}
+
+fn section<T: Debug>(out: &mut String, title: &str, all: &[Interpretation<T>]) {
+    let _ = writeln!(out, "{title}");
+    for i in all {
+        let [x, y, z] = &i.mapping;
+        let line = format!(
+            "  {:<9} {:<9} {:<9} {:>7} {}",
+            format!("{x:?}"),
+            format!("{y:?}"),
+            format!("{z:?}"),
+            i.total,
+            mark(all, i)
+        );
+        let _ = writeln!(out, "{}", line.trim_end());
+    }
+}
+
+fn csv_rows<T: Debug>(out: &mut String, part: u8, all: &[Interpretation<T>]) {
+    for i in all {
+        let [x, y, z] = &i.mapping;
+        let _ = writeln!(out, "{part},{x:?},{y:?},{z:?},{},{}", i.total, mark(all, i));
+    }
+}
@@ -0,0 +1,292 @@
+use std::collections::HashMap;
+
+use anyhow::{anyhow, bail, Result};
+
+use super::{MatchResult, DAY};
+use crate::error::ParseError;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Weapon {
+    pub name: String,
+    pub score: usize,
+}
+
+// One line of a strategy guide. Weapons are indices into `Game::weapons`, and
+// `our_result` is only known when the second letter also names an outcome.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Round {
+    pub them: usize,
+    pub us: usize,
+    pub our_result: Option<MatchResult>,
+}
+
+// A rock-paper-scissors style game: the weapons, which weapon beats which, what
+// each outcome is worth and which letters of the strategy guide mean what.
+#[derive(Debug, Clone)]
+pub struct Game {
+    pub weapons: Vec<Weapon>,
+    // beats[a][b] is true when weapon a beats weapon b
+    beats: Vec<Vec<bool>>,
+    win: usize,
+    draw: usize,
+    loss: usize,
+    them: HashMap<String, usize>,
+    us: HashMap<String, usize>,
+    results: HashMap<String, MatchResult>,
+}
+
+impl Game {
+    // The puzzle's game, with A/B/C and X/Y/Z as in the strategy guide
+    pub fn standard() -> Self {
+        Game::cyclic(&["Rock", "Paper", "Scissors"]).unwrap()
+    }
+
+    // An odd number of weapons where each one beats the half of the others that
+    // come right before it in the list, wrapping around. Weapons score 1, 2, ...
+    // in order, the opponent's letters start at A and ours end at Z.
+    pub fn cyclic(names: &[&str]) -> Result<Self> {
+        let n = names.len();
+        if n.is_multiple_of(2) || n > 26 {
+            bail!("a cyclic game needs an odd number of weapons, at most 26");
+        }
+
+        let mut game = Game::empty();
+        for (i, name) in names.iter().enumerate() {
+            let them = char::from(b'A' + i as u8).to_string();
+            let us = char::from(b'Z' + 1 + i as u8 - n as u8).to_string();
+            game.add_weapon(name, i + 1, &them, &us)?;
+        }
+        game.make_cyclic()?;
+
+        if n >= 3 {
+            for (letter, result) in [
+                ("X", MatchResult::Loss),
+                ("Y", MatchResult::Draw),
+                ("Z", MatchResult::Win),
+            ] {
+                game.results.insert(letter.to_string(), result);
+            }
+        }
+
+        Ok(game)
+    }
+
+    // Reads a rule set, one directive per line, `#` starts a comment:
+    //
+    //   weapon NAME SCORE THEM_LETTER US_LETTER
+    //   beats NAME NAME...      NAME beats each of the other weapons listed
+    //   cyclic                  each weapon beats the half listed before it
+    //   win|draw|loss SCORE [LETTER]
+    //
+    // Outcomes score 6, 3 and 0 unless specified, and a pair of weapons where
+    // neither beats the other is a draw.
+    pub fn parse(rules: &str) -> Result<Self> {
+        let mut game = Game::empty();
+        let mut cyclic = false;
+
+        for (i, line) in rules.lines().enumerate() {
+            let line_no = i + 1;
+            let line = line.split('#').next().unwrap_or_default();
+            let words = line.split_whitespace().collect::<Vec<_>>();
+            let Some((directive, args)) = words.split_first() else {
+                continue;
+            };
+
+            let result = match (*directive, args) {
+                ("weapon", [name, score, them, us]) => score
+                    .parse()
+                    .map_err(|_| anyhow!("invalid score {score}"))
+                    .and_then(|score| game.add_weapon(name, score, them, us)),
+                ("beats", [winner, losers @ ..]) if !losers.is_empty() => losers
+                    .iter()
+                    .try_for_each(|loser| game.add_beats(winner, loser)),
+                ("cyclic", []) => {
+                    cyclic = true;
+                    Ok(())
+                }
+                ("win" | "draw" | "loss", [score, letter @ ..]) if letter.len() <= 1 => {
+                    let result = match *directive {
+                        "win" => MatchResult::Win,
+                        "draw" => MatchResult::Draw,
+                        _ => MatchResult::Loss,
+                    };
+                    game.set_outcome(result, score, letter.first().copied())
+                }
+                _ => Err(anyhow!("invalid directive: {}", line.trim())),
+            };
+            result.map_err(|e| anyhow!("line {line_no}: {e}"))?;
+        }
+
+        if game.weapons.is_empty() {
+            bail!("no weapons defined");
+        }
+        if cyclic {
+            game.make_cyclic()?;
+        }
+
+        Ok(game)
+    }
+
+    fn empty() -> Self {
+        Game {
+            weapons: Vec::new(),
+            beats: Vec::new(),
+            win: 6,
+            draw: 3,
+            loss: 0,
+            them: HashMap::new(),
+            us: HashMap::new(),
+            results: HashMap::new(),
+        }
+    }
+
+    fn add_weapon(&mut self, name: &str, score: usize, them: &str, us: &str) -> Result<()> {
+        if self.weapon(name).is_some() {
+            bail!("weapon {name} is defined twice");
+        }
+        let i = self.weapons.len();
+        if self.them.insert(them.to_string(), i).is_some() {
+            bail!("letter {them} already names an opponent weapon");
+        }
+        if self.us.insert(us.to_string(), i).is_some() {
+            bail!("letter {us} already names one of our weapons");
+        }
+
+        self.weapons.push(Weapon {
+            name: name.to_string(),
+            score,
+        });
+        for row in self.beats.iter_mut() {
+            row.push(false);
+        }
+        self.beats.push(vec![false; i + 1]);
+        Ok(())
+    }
+
+    fn add_beats(&mut self, winner: &str, loser: &str) -> Result<()> {
+        let find = |name| {
+            self.weapon(name)
+                .ok_or_else(|| anyhow!("unknown weapon {name}"))
+        };
+        let (w, l) = (find(winner)?, find(loser)?);
+        if w == l {
+            bail!("{winner} cannot beat itself");
+        }
+        if self.beats[l][w] {
+            bail!("{winner} and {loser} cannot beat each other");
+        }
+        self.beats[w][l] = true;
+        Ok(())
+    }
+
+    fn make_cyclic(&mut self) -> Result<()> {
+        let n = self.weapons.len();
+        if n.is_multiple_of(2) {
+            bail!("a cyclic game needs an odd number of weapons");
+        }
+        for w in 0..n {
+            for step in 1..=n / 2 {
+                let l = (w + n - step) % n;
+                if self.beats[l][w] {
+                    bail!("cyclic conflicts with an explicit beats");
+                }
+                self.beats[w][l] = true;
+            }
+        }
+        Ok(())
+    }
+
+    fn set_outcome(
+        &mut self,
+        result: MatchResult,
+        score: &str,
+        letter: Option<&str>,
+    ) -> Result<()> {
+        let score = score
+            .parse()
+            .map_err(|_| anyhow!("invalid score {score}"))?;
+        match result {
+            MatchResult::Win => self.win = score,
+            MatchResult::Draw => self.draw = score,
+            MatchResult::Loss => self.loss = score,
+        }
+        if let Some(letter) = letter {
+            if self.results.insert(letter.to_string(), result).is_some() {
+                bail!("letter {letter} already names an outcome");
+            }
+        }
+        Ok(())
+    }
+
+    pub fn weapon(&self, name: &str) -> Option<usize> {
+        self.weapons.iter().position(|w| w.name == name)
+    }
+
+    pub fn play(&self, us: usize, them: usize) -> MatchResult {
+        if self.beats[us][them] {
+            MatchResult::Win
+        } else if self.beats[them][us] {
+            MatchResult::Loss
+        } else {
+            MatchResult::Draw
+        }
+    }
+
+    // The first weapon, in definition order, that gets `result` against `them`
+    pub fn required_for(&self, result: MatchResult, them: usize) -> Option<usize> {
+        (0..self.weapons.len()).find(|us| self.play(*us, them) == result)
+    }
+
+    pub fn outcome_score(&self, result: MatchResult) -> usize {
+        match result {
+            MatchResult::Win => self.win,
+            MatchResult::Draw => self.draw,
+            MatchResult::Loss => self.loss,
+        }
+    }
+
+    pub fn parse_rounds(&self, input: &str) -> Result<Vec<Round>> {
+        input
+            .lines()
+            .map(|line| {
+                let mut parts = line.split(' ');
+                let (Some(them), Some(us), None) = (parts.next(), parts.next(), parts.next())
+                else {
+                    return Err(ParseError::at(DAY, input, line, "expected two letters").into());
+                };
+                let unknown = |token| ParseError::at(DAY, input, token, "unknown letter");
+
+                Ok(Round {
+                    them: *self.them.get(them).ok_or_else(|| unknown(them))?,
+                    us: *self.us.get(us).ok_or_else(|| unknown(us))?,
+                    our_result: self.results.get(us).copied(),
+                })
+            })
+            .collect()
+    }
+
+    // What we score for playing `us` against `them`
+    pub fn play_score(&self, us: usize, them: usize) -> usize {
+        self.weapons[us].score + self.outcome_score(self.play(us, them))
+    }
+
+    // Part 1 rules: the second letter is the weapon we play
+    pub fn score(&self, round: &Round) -> usize {
+        self.play_score(round.us, round.them)
+    }
+
+    // Part 2 rules: the second letter is the outcome we need
+    pub fn fixed_score(&self, round: &Round) -> Option<usize> {
+        let us = self.required_for(round.our_result?, round.them)?;
+        Some(self.play_score(us, round.them))
+    }
+
+    pub fn total(&self, rounds: &[Round]) -> usize {
+        rounds.iter().map(|r| self.score(r)).sum()
+    }
+
+    // None if a round has no outcome letter or the outcome can't be reached
+    pub fn fixed_total(&self, rounds: &[Round]) -> Option<usize> {
+        rounds.iter().map(|r| self.fixed_score(r)).sum()
+    }
+}
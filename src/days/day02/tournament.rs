@@ -0,0 +1,327 @@
+use std::fmt::Write;
+
+use super::{game::Game, Match, MatchResult, RPS};
+
+// SplitMix64, enough to make the random strategy repeatable from a seed
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    pub fn below(&mut self, n: u64) -> u64 {
+        self.next_u64() % n
+    }
+}
+
+// A player in the tournament. `reset` is called before every match so the
+// same player can face each opponent from a clean slate.
+pub trait Strategy {
+    fn name(&self) -> String;
+    fn reset(&mut self);
+    fn choose(&mut self) -> RPS;
+    fn observe(&mut self, ours: RPS, theirs: RPS);
+}
+
+// The weapon that beats `rps`
+fn beater(rps: RPS) -> RPS {
+    MatchResult::Win.required_for(&rps)
+}
+
+pub struct Fixed(pub RPS);
+
+impl Strategy for Fixed {
+    fn name(&self) -> String {
+        format!("always {:?}", self.0)
+    }
+
+    fn reset(&mut self) {}
+
+    fn choose(&mut self) -> RPS {
+        self.0
+    }
+
+    fn observe(&mut self, _ours: RPS, _theirs: RPS) {}
+}
+
+pub struct Random {
+    seed: u64,
+    rng: Rng,
+}
+
+impl Random {
+    pub fn new(seed: u64) -> Self {
+        Random {
+            seed,
+            rng: Rng::new(seed),
+        }
+    }
+}
+
+impl Strategy for Random {
+    fn name(&self) -> String {
+        format!("random ({})", self.seed)
+    }
+
+    fn reset(&mut self) {
+        self.rng = Rng::new(self.seed);
+    }
+
+    fn choose(&mut self) -> RPS {
+        RPS::ALL[self.rng.below(3) as usize]
+    }
+
+    fn observe(&mut self, _ours: RPS, _theirs: RPS) {}
+}
+
+// Plays whatever beats the opponent's most frequent weapon so far
+#[derive(Default)]
+pub struct Frequency {
+    counts: [usize; 3],
+}
+
+impl Strategy for Frequency {
+    fn name(&self) -> String {
+        "frequency".to_string()
+    }
+
+    fn reset(&mut self) {
+        self.counts = [0; 3];
+    }
+
+    fn choose(&mut self) -> RPS {
+        let most = (0..3).rev().max_by_key(|i| self.counts[*i]).unwrap_or(0);
+        beater(RPS::ALL[most])
+    }
+
+    fn observe(&mut self, _ours: RPS, theirs: RPS) {
+        self.counts[theirs.index()] += 1;
+    }
+}
+
+// Keeps a winning weapon, otherwise switches to the one that beats it
+pub struct WinStayLoseShift {
+    first: RPS,
+    next: RPS,
+}
+
+impl WinStayLoseShift {
+    pub fn new(first: RPS) -> Self {
+        WinStayLoseShift { first, next: first }
+    }
+}
+
+impl Strategy for WinStayLoseShift {
+    fn name(&self) -> String {
+        "win-stay-lose-shift".to_string()
+    }
+
+    fn reset(&mut self) {
+        self.next = self.first;
+    }
+
+    fn choose(&mut self) -> RPS {
+        self.next
+    }
+
+    fn observe(&mut self, ours: RPS, theirs: RPS) {
+        if ours.play(&theirs) != MatchResult::Win {
+            self.next = beater(ours);
+        }
+    }
+}
+
+// Plays a fixed list of weapons in order, starting over when it runs out
+pub struct Sequence {
+    name: String,
+    moves: Vec<RPS>,
+    next: usize,
+}
+
+impl Sequence {
+    pub fn new(name: impl Into<String>, moves: Vec<RPS>) -> Self {
+        Sequence {
+            name: name.into(),
+            moves,
+            next: 0,
+        }
+    }
+
+    // Our column of the strategy guide, read as weapons like in part 1
+    pub fn follow_guide(matches: &[Match]) -> Self {
+        Sequence::new("guide", matches.iter().map(|m| m.us).collect())
+    }
+}
+
+impl Strategy for Sequence {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn reset(&mut self) {
+        self.next = 0;
+    }
+
+    fn choose(&mut self) -> RPS {
+        let Some(rps) = self.moves.get(self.next % self.moves.len().max(1)) else {
+            return RPS::Rock;
+        };
+        self.next += 1;
+        *rps
+    }
+
+    fn observe(&mut self, _ours: RPS, _theirs: RPS) {}
+}
+
+// Every strategy, the guide ones reading our and the opponent's columns
+pub fn entrants(matches: &[Match], seed: u64) -> Vec<Box<dyn Strategy>> {
+    let mut entrants: Vec<Box<dyn Strategy>> = RPS::ALL
+        .into_iter()
+        .map(|rps| Box::new(Fixed(rps)) as Box<dyn Strategy>)
+        .collect();
+    entrants.push(Box::new(Random::new(seed)));
+    entrants.push(Box::new(Frequency::default()));
+    entrants.push(Box::new(WinStayLoseShift::new(RPS::Rock)));
+    entrants.push(Box::new(Sequence::follow_guide(matches)));
+    entrants.push(Box::new(Sequence::new(
+        "guide opponent",
+        matches.iter().map(|m| m.them).collect(),
+    )));
+    entrants
+}
+
+// Plays `rounds` rounds and returns both players' scores, counted like the puzzle
+pub fn play(a: &mut dyn Strategy, b: &mut dyn Strategy, rounds: usize) -> (usize, usize) {
+    a.reset();
+    b.reset();
+
+    let game = Game::standard();
+    let mut scores = (0, 0);
+    for _ in 0..rounds {
+        let (x, y) = (a.choose(), b.choose());
+        scores.0 += game.play_score(x.index(), y.index());
+        scores.1 += game.play_score(y.index(), x.index());
+        a.observe(x, y);
+        b.observe(y, x);
+    }
+    scores
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Standing {
+    pub name: String,
+    pub wins: usize,
+    pub draws: usize,
+    pub losses: usize,
+    pub score: usize,
+}
+
+impl Standing {
+    // 3 points for a match won, 1 for a draw
+    pub fn points(&self) -> usize {
+        self.wins * 3 + self.draws
+    }
+}
+
+pub struct Leaderboard {
+    pub rounds: usize,
+    // Best first
+    pub standings: Vec<Standing>,
+}
+
+// Every entrant plays every other one once. A match is won by the higher score.
+pub fn round_robin(entrants: &mut [Box<dyn Strategy>], rounds: usize) -> Leaderboard {
+    let mut standings = entrants
+        .iter()
+        .map(|e| Standing {
+            name: e.name(),
+            ..Default::default()
+        })
+        .collect::<Vec<_>>();
+
+    for i in 0..entrants.len() {
+        let (left, right) = entrants.split_at_mut(i + 1);
+        for (j, other) in right.iter_mut().enumerate() {
+            let j = i + 1 + j;
+            let (a, b) = play(left[i].as_mut(), other.as_mut(), rounds);
+
+            standings[i].score += a;
+            standings[j].score += b;
+            match a.cmp(&b) {
+                std::cmp::Ordering::Greater => {
+                    standings[i].wins += 1;
+                    standings[j].losses += 1;
+                }
+                std::cmp::Ordering::Less => {
+                    standings[i].losses += 1;
+                    standings[j].wins += 1;
+                }
+                std::cmp::Ordering::Equal => {
+                    standings[i].draws += 1;
+                    standings[j].draws += 1;
+                }
+            }
+        }
+    }
+
+    standings.sort_by(|a, b| {
+        b.points()
+            .cmp(&a.points())
+            .then_with(|| b.score.cmp(&a.score))
+    });
+    Leaderboard { rounds, standings }
+}
+
+impl Leaderboard {
+    pub fn table(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(out, "{} rounds per match", self.rounds);
+        let _ = writeln!(
+            out,
+            "{:>4} {:<20} {:>4} {:>4} {:>4} {:>6} {:>8}",
+            "rank", "strategy", "won", "draw", "lost", "points", "score"
+        );
+        for (i, s) in self.standings.iter().enumerate() {
+            let _ = writeln!(
+                out,
+                "{:>4} {:<20} {:>4} {:>4} {:>4} {:>6} {:>8}",
+                i + 1,
+                s.name,
+                s.wins,
+                s.draws,
+                s.losses,
+                s.points(),
+                s.score
+            );
+        }
+        out
+    }
+
+    pub fn csv(&self) -> String {
+        let mut out = String::from("rank,strategy,won,draw,lost,points,score\n");
+        for (i, s) in self.standings.iter().enumerate() {
+            let _ = writeln!(
+                out,
+                "{},{},{},{},{},{},{}",
+                i + 1,
+                s.name,
+                s.wins,
+                s.draws,
+                s.losses,
+                s.points(),
+                s.score
+            );
+        }
+        out
+    }
+}
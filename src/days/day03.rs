@@ -0,0 +1,301 @@
+use std::{
+    fmt::{Display, Write},
+    ops::{BitAnd, BitOr},
+};
+
+use anyhow::{anyhow, bail, Result};
+
+use crate::{answer::Answers, error::ParseError};
+
+const DAY: u8 = 3;
+
+pub const GROUP_SIZE: usize = 3;
+
+pub fn parse(input: &str) -> Result<Vec<Rucksack>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, l)| {
+            if let Some(i) = l.find(|c: char| !c.is_ascii_alphabetic()) {
+                return Err(ParseError::at(DAY, input, &l[i..], "expected an item letter").into());
+            }
+            if l.len() % 2 == 1 {
+                return Err(ParseError::at(DAY, input, l, "odd number of items").into());
+            }
+
+            let (a, b) = l.split_at(l.len() / 2);
+            Ok(Rucksack {
+                line: i + 1,
+                a: a.chars().collect(),
+                b: b.chars().collect(),
+            })
+        })
+        .collect()
+}
+
+pub fn part1(rucksacks: &[Rucksack]) -> Result<u32> {
+    rucksacks
+        .iter()
+        .map(|r| {
+            single(r.shared()).ok_or_else(|| anyhow!("{}", Problem::Shared(r.line, r.shared())))
+        })
+        .sum()
+}
+
+pub fn part2(rucksacks: &[Rucksack]) -> Result<u32> {
+    badges(rucksacks, GROUP_SIZE)
+}
+
+pub fn solve(input: &str) -> Result<Answers> {
+    let rucksacks = parse(input)?;
+    Answers::new(part1(&rucksacks), part2(&rucksacks))
+}
+
+// Sum of the badge priorities when elves are grouped `size` at a time
+pub fn badges(rucksacks: &[Rucksack], size: usize) -> Result<u32> {
+    if size == 0 || !rucksacks.len().is_multiple_of(size) {
+        bail!(
+            "{} rucksacks cannot be split into groups of {size}",
+            rucksacks.len()
+        );
+    }
+
+    rucksacks
+        .chunks(size)
+        .map(|g| {
+            let badge = common(g);
+            single(badge).ok_or_else(|| anyhow!("{}", Problem::Badge(g[0].line, badge)))
+        })
+        .sum()
+}
+
+// The priority of the only item in `items`
+fn single(items: ItemSet) -> Option<u32> {
+    let mut iter = items.iter();
+    match (iter.next(), iter.next()) {
+        (Some(item), None) => priority(item),
+        _ => None,
+    }
+}
+
+pub struct Rucksack {
+    pub line: usize,
+    a: ItemSet,
+    b: ItemSet,
+}
+
+impl Rucksack {
+    // Items found in both compartments
+    pub fn shared(&self) -> ItemSet {
+        self.a & self.b
+    }
+
+    pub fn all(&self) -> ItemSet {
+        self.a | self.b
+    }
+}
+
+// Items carried by every rucksack in the group
+pub fn common(group: &[Rucksack]) -> ItemSet {
+    group.iter().fold(ItemSet::ALL, |h, s| h & s.all())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Problem {
+    // Line of the rucksack, and the items in both compartments
+    Shared(usize, ItemSet),
+    // First line of the group, and the items all its rucksacks carry
+    Badge(usize, ItemSet),
+    // First line of the last group, and how many rucksacks it has
+    IncompleteGroup(usize, usize),
+}
+
+impl Problem {
+    pub fn line(&self) -> usize {
+        match self {
+            Problem::Shared(line, _)
+            | Problem::Badge(line, _)
+            | Problem::IncompleteGroup(line, _) => *line,
+        }
+    }
+
+    // What is wrong, without saying where
+    pub fn message(&self) -> String {
+        let items = |items: &ItemSet| items.iter().collect::<String>();
+        match self {
+            Problem::Shared(_, shared) if shared.is_empty() => {
+                "no item in both compartments".to_string()
+            }
+            Problem::Shared(_, shared) => {
+                format!("several items in both compartments: {}", items(shared))
+            }
+            Problem::Badge(_, badge) if badge.is_empty() => "no badge".to_string(),
+            Problem::Badge(_, badge) => format!("ambiguous badge: {}", items(badge)),
+            Problem::IncompleteGroup(_, size) => format!("only {size} rucksack(s)"),
+        }
+    }
+}
+
+impl Display for Problem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Problem::Shared(line, _) => write!(f, "line {line}: {}", self.message()),
+            _ => write!(f, "group at line {}: {}", self.line(), self.message()),
+        }
+    }
+}
+
+// Every rucksack that doesn't share exactly one item between its compartments,
+// and every group that doesn't have exactly one badge.
+pub fn validate(rucksacks: &[Rucksack], group_size: usize) -> Vec<Problem> {
+    let mut problems = Vec::new();
+
+    for r in rucksacks {
+        if r.shared().len() != 1 {
+            problems.push(Problem::Shared(r.line, r.shared()));
+        }
+    }
+
+    for group in rucksacks.chunks(group_size.max(1)) {
+        let line = group[0].line;
+        if group.len() < group_size {
+            problems.push(Problem::IncompleteGroup(line, group.len()));
+        } else if common(group).len() != 1 {
+            problems.push(Problem::Badge(line, common(group)));
+        }
+    }
+
+    problems.sort_by_key(Problem::line);
+    problems
+}
+
+// Every rucksack and group that doesn't have exactly one shared item or badge
+pub struct Report {
+    pub rucksacks: usize,
+    pub group_size: usize,
+    pub problems: Vec<Problem>,
+}
+
+pub fn report(input: &str, group_size: usize) -> Result<Report> {
+    let rucksacks = parse(input)?;
+    Ok(Report {
+        rucksacks: rucksacks.len(),
+        group_size,
+        problems: validate(&rucksacks, group_size),
+    })
+}
+
+impl Report {
+    pub fn table(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            "{} rucksacks, groups of {}: {} problem(s)",
+            self.rucksacks,
+            self.group_size,
+            self.problems.len()
+        );
+        for problem in self.problems.iter() {
+            let _ = writeln!(out, "{problem}");
+        }
+        out
+    }
+
+    pub fn csv(&self) -> String {
+        let mut out = String::from("line,problem\n");
+        for problem in self.problems.iter() {
+            let _ = writeln!(out, "{},{}", problem.line(), problem.message());
+        }
+        out
+    }
+}
+
+// A set of items stored as a bit mask, where bit N is the item with priority N.
+// Anything that isn't an item letter is ignored.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ItemSet(u64);
+
+impl ItemSet {
+    pub const EMPTY: ItemSet = ItemSet(0);
+    pub const ALL: ItemSet = ItemSet(((1 << 52) - 1) << 1);
+
+    pub fn insert(&mut self, item: char) {
+        if let Some(p) = priority(item) {
+            self.0 |= 1 << p;
+        }
+    }
+
+    pub fn contains(&self, item: char) -> bool {
+        priority(item).is_some_and(|p| self.0 & (1 << p) != 0)
+    }
+
+    pub fn intersection(&self, other: &ItemSet) -> ItemSet {
+        ItemSet(self.0 & other.0)
+    }
+
+    pub fn union(&self, other: &ItemSet) -> ItemSet {
+        ItemSet(self.0 | other.0)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    // Items in priority order
+    pub fn iter(&self) -> impl Iterator<Item = char> {
+        let mut bits = self.0;
+        std::iter::from_fn(move || {
+            if bits == 0 {
+                return None;
+            }
+            let p = bits.trailing_zeros();
+            bits &= bits - 1;
+            Some(item(p))
+        })
+    }
+}
+
+impl FromIterator<char> for ItemSet {
+    fn from_iter<T: IntoIterator<Item = char>>(iter: T) -> Self {
+        let mut set = ItemSet::EMPTY;
+        for item in iter {
+            set.insert(item);
+        }
+        set
+    }
+}
+
+impl BitAnd for ItemSet {
+    type Output = ItemSet;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.intersection(&rhs)
+    }
+}
+
+impl BitOr for ItemSet {
+    type Output = ItemSet;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(&rhs)
+    }
+}
+
+pub fn priority(c: char) -> Option<u32> {
+    match c {
+        'a'..='z' => Some(1 + (c as u32) - ('a' as u32)),
+        'A'..='Z' => Some(27 + (c as u32) - ('A' as u32)),
+        _ => None,
+    }
+}
+
+fn item(priority: u32) -> char {
+    match priority {
+        1..=26 => char::from(b'a' + priority as u8 - 1),
+        _ => char::from(b'A' + priority as u8 - 27),
+    }
+}
@@ -0,0 +1,264 @@
+use std::{cmp::Reverse, collections::BinaryHeap, fmt::Write};
+
+use anyhow::Result;
+
+use crate::{
+    answer::Answers,
+    error::ParseError,
+    interval::{Interval, IntervalSet},
+};
+
+const DAY: u8 = 4;
+
+pub type Assignments = Vec<Vec<Interval>>;
+
+pub fn parse(input: &str) -> Result<Assignments> {
+    input
+        .lines()
+        .map(|l| {
+            if l.is_empty() {
+                return Err(ParseError::at(DAY, input, l, "expected a range").into());
+            }
+            let parts = l.split(',');
+            let grp = parts
+                .map(|p| {
+                    let error = |message| ParseError::at(DAY, input, p, message);
+                    let (start, end) =
+                        p.split_once('-').ok_or_else(|| error("expected a range"))?;
+                    let start = start
+                        .parse::<i64>()
+                        .map_err(|_| ParseError::at(DAY, input, start, "invalid section"))?;
+                    let end = end
+                        .parse::<i64>()
+                        .map_err(|_| ParseError::at(DAY, input, end, "invalid section"))?;
+                    Interval::new(start, end).ok_or_else(|| error("range starts after its end"))
+                })
+                .collect::<Result<Vec<_>, ParseError>>()?;
+
+            Ok(grp)
+        })
+        .collect()
+}
+
+// Lines where one elf's assignment contains another's
+pub fn part1(tasks: &Assignments) -> usize {
+    tasks
+        .iter()
+        .filter(|grp| any_pair(grp, |a, b| a.contains(b) || b.contains(a)))
+        .count()
+}
+
+// Lines where two elves' assignments overlap
+pub fn part2(tasks: &Assignments) -> usize {
+    tasks
+        .iter()
+        .filter(|grp| any_pair(grp, |a, b| a.overlaps(b)))
+        .count()
+}
+
+pub fn solve(input: &str) -> Result<Answers> {
+    let tasks = parse(input)?;
+    Answers::new(part1(&tasks), part2(&tasks))
+}
+
+// Every section assigned to at least one elf
+pub fn coverage(tasks: &Assignments) -> IntervalSet {
+    tasks.iter().flatten().copied().collect()
+}
+
+fn any_pair(grp: &[Interval], f: impl Fn(&Interval, &Interval) -> bool) -> bool {
+    grp.iter()
+        .enumerate()
+        .any(|(i, a)| grp[i + 1..].iter().any(|b| f(a, b)))
+}
+
+// An elf by its line and position on that line, both 1-based
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Elf {
+    pub line: usize,
+    pub position: usize,
+}
+
+pub fn elves(tasks: &Assignments) -> Vec<(Elf, Interval)> {
+    tasks
+        .iter()
+        .enumerate()
+        .flat_map(|(line, grp)| {
+            grp.iter().enumerate().map(move |(position, interval)| {
+                let elf = Elf {
+                    line: line + 1,
+                    position: position + 1,
+                };
+                (elf, *interval)
+            })
+        })
+        .collect()
+}
+
+// Sweeps over the assignments in order of their start, handing each elf to `f`
+// along with the elves whose assignments haven't ended yet, which are exactly
+// the earlier ones it overlaps.
+fn sweep(tasks: &Assignments, mut f: impl FnMut(Elf, &mut dyn ExactSizeIterator<Item = Elf>)) {
+    let mut elves = elves(tasks);
+    elves.sort_by_key(|(_, interval)| interval.start);
+
+    // Assignments still open, soonest to end on top
+    let mut active: BinaryHeap<Reverse<(i64, usize)>> = BinaryHeap::new();
+    for (i, (elf, interval)) in elves.iter().enumerate() {
+        while active
+            .peek()
+            .is_some_and(|Reverse((end, _))| *end < interval.start)
+        {
+            active.pop();
+        }
+        f(*elf, &mut active.iter().map(|Reverse((_, j))| elves[*j].0));
+        active.push(Reverse((interval.end, i)));
+    }
+}
+
+// Every pair of elves in the file whose assignments share a section
+pub fn overlapping_pairs(tasks: &Assignments) -> Vec<(Elf, Elf)> {
+    let mut pairs = Vec::new();
+    sweep(tasks, |elf, open| {
+        pairs.extend(open.map(|other| (other.min(elf), other.max(elf))));
+    });
+    pairs.sort();
+    pairs
+}
+
+// As many as `overlapping_pairs` finds, without listing them
+pub fn count_overlapping_pairs(tasks: &Assignments) -> usize {
+    let mut count = 0;
+    sweep(tasks, |_, open| count += open.len());
+    count
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Contested {
+    // The first run of sections with the most elves assigned
+    pub sections: Interval,
+    pub elves: usize,
+}
+
+// Sweeps over the points where assignments start and end, tracking how many
+// elves cover the sections in between. An assignment running up to `i64::MAX`
+// never ends.
+pub fn most_contested(tasks: &Assignments) -> Option<Contested> {
+    let mut events = tasks
+        .iter()
+        .flatten()
+        .flat_map(|i| {
+            let end = i.end.checked_add(1).map(|after| (after, -1));
+            [Some((i.start, 1)), end].into_iter().flatten()
+        })
+        .collect::<Vec<(i64, i64)>>();
+    events.sort();
+
+    let mut best: Option<Contested> = None;
+    let mut count = 0;
+    let mut i = 0;
+    while i < events.len() {
+        let at = events[i].0;
+        while i < events.len() && events[i].0 == at {
+            count += events[i].1;
+            i += 1;
+        }
+
+        let end = events.get(i).map_or(i64::MAX, |next| next.0 - 1);
+        if let Some(b) = best
+            .as_mut()
+            .filter(|b| b.elves == count as usize && b.sections.end.checked_add(1) == Some(at))
+        {
+            b.sections.end = end;
+        } else if count > 0 && best.is_none_or(|b| count as usize > b.elves) {
+            best = Some(Contested {
+                sections: Interval { start: at, end },
+                elves: count as usize,
+            });
+        }
+    }
+
+    best
+}
+
+// The most elves assigned to any one section
+pub fn max_coverage(tasks: &Assignments) -> usize {
+    most_contested(tasks).map_or(0, |c| c.elves)
+}
+
+// Which sections are assigned to somebody, and which to nobody
+pub struct Report {
+    pub coverage: IntervalSet,
+    // The gaps between the lowest and highest assigned sections
+    pub uncovered: IntervalSet,
+    pub overlapping_pairs: usize,
+    pub most_contested: Option<Contested>,
+}
+
+pub fn report(input: &str) -> Result<Report> {
+    let tasks = parse(input)?;
+    let coverage = coverage(&tasks);
+    let uncovered = coverage
+        .span()
+        .map_or_else(IntervalSet::new, |span| coverage.uncovered(span));
+    Ok(Report {
+        overlapping_pairs: count_overlapping_pairs(&tasks),
+        most_contested: most_contested(&tasks),
+        coverage,
+        uncovered,
+    })
+}
+
+impl Report {
+    pub fn table(&self) -> String {
+        let mut out = String::new();
+        let Some(span) = self.coverage.span() else {
+            return out;
+        };
+        let uncovered = if self.uncovered.is_empty() {
+            "none".to_string()
+        } else {
+            self.uncovered.to_string()
+        };
+
+        let _ = writeln!(
+            out,
+            "Sections {span}: {} covered, {} uncovered",
+            self.coverage.len(),
+            self.uncovered.len()
+        );
+        let _ = writeln!(out, "Covered: {}", self.coverage);
+        let _ = writeln!(out, "Uncovered: {uncovered}");
+        let _ = writeln!(out, "Overlapping pairs: {}", self.overlapping_pairs);
+        if let Some(contested) = self.most_contested {
+            let _ = writeln!(
+                out,
+                "Most contested: {} ({} elves)",
+                contested.sections, contested.elves
+            );
+        }
+        out
+    }
+
+    // Covered and uncovered runs in order, the totals are in `table`
+    pub fn csv(&self) -> String {
+        if self.coverage.is_empty() {
+            return String::new();
+        }
+
+        let mut rows = self
+            .coverage
+            .intervals()
+            .iter()
+            .map(|i| (i, true))
+            .chain(self.uncovered.intervals().iter().map(|i| (i, false)))
+            .collect::<Vec<_>>();
+        rows.sort();
+
+        let mut out = String::from("start,end,covered\n");
+        for (i, covered) in rows {
+            let _ = writeln!(out, "{},{},{covered}", i.start, i.end);
+        }
+        out
+    }
+}
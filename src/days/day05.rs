@@ -0,0 +1,596 @@
+use std::fmt::{Display, Write};
+
+use anyhow::{anyhow, bail, Result};
+use regex::Regex;
+
+use crate::{answer::Answers, error::ParseError};
+
+const DAY: u8 = 5;
+
+pub type Command = (usize, usize, usize);
+
+// Crates from the bottom of each stack to the top. Labels can be longer than one
+// character, the top crates are concatenated to get the answer.
+pub struct Puzzle {
+    pub stacks: Vec<Vec<String>>,
+    pub commands: Vec<Command>,
+    // Line of the first command, the others follow one per line
+    pub first_line: usize,
+}
+
+impl Puzzle {
+    // Line of the command run at `step`, counting from 1
+    pub fn line(&self, step: usize) -> usize {
+        self.first_line + step - 1
+    }
+}
+
+pub fn parse(input: &str) -> Result<Puzzle> {
+    let mut parts = input.splitn(2, "\n\n");
+
+    let diagram = parts.next().unwrap_or_default();
+    let stacks = parse_diagram(input, diagram)?;
+
+    let commands_str = parts.next().ok_or_else(|| {
+        ParseError::at(
+            DAY,
+            input,
+            &input[input.len()..],
+            "missing rearrangement procedure",
+        )
+    })?;
+
+    let re = Regex::new(r#"^move (\d+) from (\d+) to (\d+)$"#).unwrap();
+
+    let commands = commands_str
+        .lines()
+        .map(|line| {
+            let caps = re
+                .captures(line)
+                .ok_or_else(|| ParseError::at(DAY, input, line, "expected `move N from A to B`"))?;
+            let number = |i: usize| {
+                let digits = &caps[i];
+                digits
+                    .parse::<usize>()
+                    .map_err(|_| ParseError::at(DAY, input, digits, "invalid number"))
+            };
+            Ok((number(1)?, number(2)?, number(3)?))
+        })
+        .collect::<Result<Vec<Command>>>()?;
+
+    Ok(Puzzle {
+        stacks,
+        commands,
+        first_line: diagram.lines().count() + 2,
+    })
+}
+
+// A run of text on a diagram line, with the columns it spans in characters
+struct Token<'a> {
+    text: &'a str,
+    start: usize,
+    end: usize,
+}
+
+impl Token<'_> {
+    // Doubled to stay in integers
+    fn center(&self) -> usize {
+        self.start + self.end
+    }
+}
+
+fn tokens(line: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut current: Option<(usize, usize)> = None;
+
+    for (col, (i, ch)) in line.char_indices().chain([(line.len(), ' ')]).enumerate() {
+        match (ch.is_whitespace(), current) {
+            (false, None) => current = Some((i, col)),
+            (true, Some((start, start_col))) => {
+                tokens.push(Token {
+                    text: &line[start..i],
+                    start: start_col,
+                    end: col - 1,
+                });
+                current = None;
+            }
+            _ => {}
+        }
+    }
+    tokens
+}
+
+// The number row at the bottom of the diagram gives the stacks and the column
+// each one sits in. Every crate above it belongs to the stack whose number is
+// closest to the crate's center, so lines can be ragged and labels can be wider
+// than one character.
+fn parse_diagram(input: &str, diagram: &str) -> Result<Vec<Vec<String>>> {
+    let mut lines = diagram.lines().rev().skip_while(|l| l.trim().is_empty());
+    let numbers = lines.next().unwrap_or_default();
+    let columns = tokens(numbers);
+
+    if columns.is_empty() {
+        return Err(ParseError::at(DAY, input, numbers, "expected a row of stack numbers").into());
+    }
+    for (i, column) in columns.iter().enumerate() {
+        if column.text.parse::<usize>().ok() != Some(i + 1) {
+            let message = format!("expected stack number {}", i + 1);
+            return Err(ParseError::at(DAY, input, column.text, message).into());
+        }
+    }
+
+    let mut stacks = vec![Vec::new(); columns.len()];
+    for line in lines {
+        let mut seen = vec![false; columns.len()];
+
+        for token in tokens(line) {
+            let Some(label) = token
+                .text
+                .strip_prefix('[')
+                .and_then(|t| t.strip_suffix(']'))
+                .filter(|t| !t.is_empty() && !t.contains(['[', ']']))
+            else {
+                return Err(
+                    ParseError::at(DAY, input, token.text, "expected a crate like [X]").into(),
+                );
+            };
+
+            let (stack, column) = columns
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, c)| c.center().abs_diff(token.center()))
+                .unwrap_or((0, &columns[0]));
+            if column.end < token.start || token.end < column.start {
+                return Err(
+                    ParseError::at(DAY, input, token.text, "crate is not above a stack").into(),
+                );
+            }
+            if seen[stack] {
+                return Err(ParseError::at(
+                    DAY,
+                    input,
+                    token.text,
+                    "two crates above the same stack",
+                )
+                .into());
+            }
+            seen[stack] = true;
+
+            stacks[stack].push(label.to_string());
+        }
+    }
+
+    Ok(stacks)
+}
+
+pub fn part1(puzzle: &Puzzle) -> Result<String> {
+    Ok(tops(&simulate(&CrateMover9000, puzzle)?))
+}
+
+pub fn part2(puzzle: &Puzzle) -> Result<String> {
+    Ok(tops(&simulate(&CrateMover9001, puzzle)?))
+}
+
+pub fn solve(input: &str) -> Result<Answers> {
+    let puzzle = parse(input)?;
+    Answers::new(part1(&puzzle), part2(&puzzle))
+}
+
+// The label of the top crate of every stack that isn't empty
+pub fn tops(stacks: &[Vec<String>]) -> String {
+    stacks.iter().filter_map(|s| s.last()).cloned().collect()
+}
+
+// How a crane sets down the crates it takes off the top of a stack. `block` is
+// in stack order, bottom first, and is rearranged into the order the crates end
+// up in on the destination stack.
+pub trait Crane {
+    fn name(&self) -> String;
+    fn arrange(&self, block: &mut [String]);
+
+    // The commands that put a block moved by `command` back where it was
+    fn inverse(&self, (count, from, to): Command) -> Vec<Command> {
+        vec![(count, to, from)]
+    }
+}
+
+// Moves one crate at a time, so the block ends up upside down
+pub struct CrateMover9000;
+
+impl Crane for CrateMover9000 {
+    fn name(&self) -> String {
+        "CrateMover 9000".to_string()
+    }
+
+    fn arrange(&self, block: &mut [String]) {
+        block.reverse();
+    }
+}
+
+// Moves the whole block at once, keeping its order
+pub struct CrateMover9001;
+
+impl Crane for CrateMover9001 {
+    fn name(&self) -> String {
+        "CrateMover 9001".to_string()
+    }
+
+    fn arrange(&self, _block: &mut [String]) {}
+}
+
+// Lifts at most `capacity` crates at a time, top ones first, each lift keeping
+// its order. A capacity of 1 is a CrateMover 9000.
+pub struct Limited {
+    pub capacity: usize,
+}
+
+impl Crane for Limited {
+    fn name(&self) -> String {
+        format!("limited to {} crates", self.capacity)
+    }
+
+    fn arrange(&self, block: &mut [String]) {
+        block.reverse();
+        for chunk in block.chunks_mut(self.capacity.max(1)) {
+            chunk.reverse();
+        }
+    }
+
+    // The last lift ends up on top, and it's the only one that can be short
+    fn inverse(&self, (count, from, to): Command) -> Vec<Command> {
+        let capacity = self.capacity.max(1);
+        let mut commands = Vec::new();
+        if count % capacity != 0 {
+            commands.push((count % capacity, to, from));
+        }
+        commands.extend(std::iter::repeat_n((capacity, to, from), count / capacity));
+        commands
+    }
+}
+
+// Moves the whole block at once but flips it over on the way
+pub struct Flipping;
+
+impl Crane for Flipping {
+    fn name(&self) -> String {
+        "flipping".to_string()
+    }
+
+    fn arrange(&self, block: &mut [String]) {
+        block.reverse();
+    }
+}
+
+// Crane models by name: 9000, 9001, flip or limit:N
+pub fn crane(name: &str) -> Option<Box<dyn Crane>> {
+    match name {
+        "9000" => Some(Box::new(CrateMover9000)),
+        "9001" => Some(Box::new(CrateMover9001)),
+        "flip" => Some(Box::new(Flipping)),
+        _ => {
+            let capacity = name.strip_prefix("limit:")?.parse().ok()?;
+            (capacity > 0).then(|| Box::new(Limited { capacity }) as Box<dyn Crane>)
+        }
+    }
+}
+
+// The stacks after running the whole procedure with the given crane
+pub fn simulate(crane: &dyn Crane, puzzle: &Puzzle) -> Result<Vec<Vec<String>>, InvalidCommand> {
+    let mut executor = Executor::new(crane, puzzle);
+    executor.run()?;
+    Ok(executor.stacks)
+}
+
+// The crates left on top with each of the given crane models
+pub struct Report {
+    pub stacks: usize,
+    pub moves: usize,
+    // Name the crane was asked for, its full name and the top crates
+    pub cranes: Vec<(String, String, String)>,
+}
+
+pub fn report(input: &str, cranes: &[String]) -> Result<Report> {
+    let puzzle = parse(input)?;
+    let cranes = cranes
+        .iter()
+        .map(|name| {
+            let model = crane(name).ok_or_else(|| anyhow!("unknown crane {name}"))?;
+            let tops = tops(&simulate(model.as_ref(), &puzzle)?);
+            Ok((name.clone(), model.name(), tops))
+        })
+        .collect::<Result<_>>()?;
+    Ok(Report {
+        stacks: puzzle.stacks.len(),
+        moves: puzzle.commands.len(),
+        cranes,
+    })
+}
+
+impl Report {
+    pub fn table(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(out, "{} stacks, {} moves", self.stacks, self.moves);
+        for (_, name, tops) in self.cranes.iter() {
+            let _ = writeln!(out, "{name}: {tops}");
+        }
+        out
+    }
+
+    pub fn csv(&self) -> String {
+        let mut out = String::from("crane,tops\n");
+        for (crane, _, tops) in self.cranes.iter() {
+            let _ = writeln!(out, "{crane},{tops}");
+        }
+        out
+    }
+}
+
+// Every arrangement the stacks go through, from the starting one to the one
+// left after the last command, so the state after step `n` is item `n`. An
+// invalid command ends the replay with its error.
+pub fn replay<'a>(
+    crane: &'a dyn Crane,
+    puzzle: &'a Puzzle,
+) -> impl Iterator<Item = Result<Vec<Vec<String>>, InvalidCommand>> + 'a {
+    let mut executor = Executor::new(crane, puzzle);
+    let mut failed = false;
+    let start = Ok(executor.stacks.clone());
+    std::iter::once(start).chain(std::iter::from_fn(move || {
+        if failed {
+            return None;
+        }
+        match executor.forward() {
+            Ok(true) => Some(Ok(executor.stacks.clone())),
+            Ok(false) => None,
+            Err(e) => {
+                failed = true;
+                Some(Err(e))
+            }
+        }
+    }))
+}
+
+// What to show of a rearrangement
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum View {
+    // The diagram after every command
+    Steps,
+    // The diagram after the given number of commands
+    Step(usize),
+    // The final diagram against the starting one
+    Diff,
+    // The commands that undo the whole procedure
+    Inverse,
+}
+
+// Runs the procedure with the given crane and shows it as `view` asks
+pub fn watch(input: &str, crane: &dyn Crane, view: View) -> Result<String> {
+    let puzzle = parse(input)?;
+    let mut executor = Executor::new(crane, &puzzle);
+    executor.run()?;
+
+    let describe = |n: usize| match n.checked_sub(1).map(|i| puzzle.commands[i]) {
+        Some((count, from, to)) => format!("Step {n}: move {count} from {from} to {to}"),
+        None => "Start".to_string(),
+    };
+
+    let mut out = String::new();
+    let _ = writeln!(out, "Crane: {}", crane.name());
+    match view {
+        View::Steps => {
+            for (n, stacks) in replay(crane, &puzzle).enumerate() {
+                let _ = writeln!(out, "{}\n{}", describe(n), render(&stacks?));
+            }
+        }
+        View::Step(n) => {
+            if n > executor.steps() {
+                bail!(
+                    "step must be at most {}, the number of moves",
+                    executor.steps()
+                );
+            }
+            executor.rewind(n);
+            let _ = write!(out, "{}\n{}", describe(n), render(executor.stacks()));
+        }
+        View::Diff => out.push_str(&diff(&puzzle.stacks, executor.stacks())),
+        View::Inverse => {
+            for (count, from, to) in executor.inverse() {
+                let _ = writeln!(out, "move {count} from {from} to {to}");
+            }
+        }
+    }
+    Ok(out)
+}
+
+// Draws the stacks the way the input does, so a parsed diagram renders back to
+// the same text
+pub fn render(stacks: &[Vec<String>]) -> String {
+    let height = stacks.iter().map(Vec::len).max().unwrap_or(0);
+    rows(stacks, column_width(&[stacks]), height).join("\n") + "\n"
+}
+
+// Both arrangements drawn with the same columns and height, with the rows that
+// changed marked the way a line diff would
+pub fn diff(before: &[Vec<String>], after: &[Vec<String>]) -> String {
+    let height = before.iter().chain(after).map(Vec::len).max().unwrap_or(0);
+    let width = column_width(&[before, after]);
+    let before = rows(before, width, height);
+    let after = rows(after, width, height);
+
+    let mut out = String::new();
+    for (old, new) in before.iter().zip(after.iter()) {
+        let lines = if old == new {
+            vec![format!("  {old}")]
+        } else {
+            vec![format!("- {old}"), format!("+ {new}")]
+        };
+        for line in lines {
+            out.push_str(line.trim_end());
+            out.push('\n');
+        }
+    }
+    out
+}
+
+// Wide enough for the longest label in brackets and the highest stack number
+fn column_width(arrangements: &[&[Vec<String>]]) -> usize {
+    arrangements
+        .iter()
+        .flat_map(|stacks| {
+            let numbers = stacks.len().to_string().len();
+            stacks
+                .iter()
+                .flatten()
+                .map(|c| c.len() + 2)
+                .chain([numbers])
+        })
+        .max()
+        .unwrap_or(0)
+        .max(3)
+}
+
+// Crate rows from the top down, padded to `height`, then the number row. As in
+// the puzzle's input, crate rows stop at their last crate but the number row
+// runs to the end of the last column.
+fn rows(stacks: &[Vec<String>], width: usize, height: usize) -> Vec<String> {
+    let cells = |cells: Vec<String>| {
+        cells
+            .iter()
+            .map(|cell| format!("{cell:^width$}"))
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    let line = |crates: Vec<String>| cells(crates).trim_end().to_string();
+
+    let mut rows = (0..height)
+        .rev()
+        .map(|level| {
+            line(
+                stacks
+                    .iter()
+                    .map(|s| s.get(level).map_or(String::new(), |c| format!("[{c}]")))
+                    .collect(),
+            )
+        })
+        .collect::<Vec<_>>();
+    rows.push(cells((1..=stacks.len()).map(|n| n.to_string()).collect()));
+    rows
+}
+
+// Why a command can't be carried out
+pub fn check(stacks: &[Vec<String>], (count, from, to): Command) -> Option<String> {
+    for stack in [from, to] {
+        if stack == 0 || stack > stacks.len() {
+            return Some(format!("there is no stack {stack}"));
+        }
+    }
+    let available = stacks[from - 1].len();
+    (count > available).then(|| format!("stack {from} only has {available} crate(s)"))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidCommand {
+    pub step: usize,
+    pub line: usize,
+    pub command: Command,
+    pub reason: String,
+}
+
+impl Display for InvalidCommand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (count, from, to) = self.command;
+        write!(
+            f,
+            "day {DAY}, line {}: cannot move {count} from {from} to {to}: {}",
+            self.line, self.reason
+        )
+    }
+}
+
+impl std::error::Error for InvalidCommand {}
+
+// Runs the procedure one command at a time, checking each one first and keeping
+// the crates every step lifted so steps can be undone.
+pub struct Executor<'a> {
+    crane: &'a dyn Crane,
+    puzzle: &'a Puzzle,
+    stacks: Vec<Vec<String>>,
+    // The crates each step took off its source stack, bottom first
+    undo: Vec<Vec<String>>,
+}
+
+impl<'a> Executor<'a> {
+    pub fn new(crane: &'a dyn Crane, puzzle: &'a Puzzle) -> Self {
+        Executor {
+            crane,
+            puzzle,
+            stacks: puzzle.stacks.clone(),
+            undo: Vec::new(),
+        }
+    }
+
+    pub fn stacks(&self) -> &[Vec<String>] {
+        &self.stacks
+    }
+
+    // Number of commands carried out so far
+    pub fn steps(&self) -> usize {
+        self.undo.len()
+    }
+
+    // Carries out the next command, returning false once there are none left
+    pub fn forward(&mut self) -> Result<bool, InvalidCommand> {
+        let Some(&command) = self.puzzle.commands.get(self.steps()) else {
+            return Ok(false);
+        };
+        if let Some(reason) = check(&self.stacks, command) {
+            return Err(InvalidCommand {
+                step: self.steps() + 1,
+                line: self.puzzle.line(self.steps() + 1),
+                command,
+                reason,
+            });
+        }
+
+        let (count, from, to) = command;
+        let source = &mut self.stacks[from - 1];
+        let mut block = source.split_off(source.len() - count);
+        self.undo.push(block.clone());
+        self.crane.arrange(&mut block);
+        self.stacks[to - 1].extend(block);
+        Ok(true)
+    }
+
+    // Carries out every remaining command, stopping at the first invalid one
+    pub fn run(&mut self) -> Result<(), InvalidCommand> {
+        while self.forward()? {}
+        Ok(())
+    }
+
+    // Takes back the last command, returning false if there was none
+    pub fn back(&mut self) -> bool {
+        let Some(block) = self.undo.pop() else {
+            return false;
+        };
+        let (count, from, to) = self.puzzle.commands[self.steps()];
+        let target = &mut self.stacks[to - 1];
+        target.truncate(target.len() - count);
+        self.stacks[from - 1].extend(block);
+        true
+    }
+
+    // Undoes commands until only the first `step` have been carried out
+    pub fn rewind(&mut self, step: usize) {
+        while self.steps() > step && self.back() {}
+    }
+
+    // A procedure that takes the stacks from where they are now back to where
+    // they started, using the same crane
+    pub fn inverse(&self) -> Vec<Command> {
+        self.puzzle.commands[..self.steps()]
+            .iter()
+            .rev()
+            .flat_map(|command| self.crane.inverse(*command))
+            .collect()
+    }
+}
@@ -0,0 +1,236 @@
+use std::{fmt::Write, io::BufRead, ops::Range};
+
+use anyhow::{anyhow, Result};
+
+use crate::answer::Answers;
+
+pub const PACKET: usize = 4;
+pub const MESSAGE: usize = 14;
+
+pub fn parse(input: &str) -> Result<Vec<u8>> {
+    Ok(input.trim_end().as_bytes().to_vec())
+}
+
+pub fn part1(data: &[u8]) -> Result<usize> {
+    first_marker(data, PACKET).ok_or_else(|| anyhow!("no start-of-packet marker found"))
+}
+
+pub fn part2(data: &[u8]) -> Result<usize> {
+    first_marker(data, MESSAGE).ok_or_else(|| anyhow!("no start-of-message marker found"))
+}
+
+pub fn solve(input: &str) -> Result<Answers> {
+    let data = parse(input)?;
+    Answers::new(part1(&data), part2(&data))
+}
+
+// Tracks how many of the most recent bytes are all different, using the last
+// position each byte value was seen at, so every byte is O(1) whatever the
+// window size.
+pub struct Detector {
+    size: usize,
+    // Bytes pushed so far
+    position: usize,
+    // Where the current run of distinct bytes starts
+    start: usize,
+    // One past the position each byte was last seen at, 0 if never
+    last_seen: [usize; 256],
+}
+
+impl Detector {
+    pub fn new(size: usize) -> Self {
+        Detector {
+            size,
+            position: 0,
+            start: 0,
+            last_seen: [0; 256],
+        }
+    }
+
+    // Adds the next byte and tells whether the last `size` bytes, this one
+    // included, are all different
+    pub fn push(&mut self, byte: u8) -> bool {
+        let seen = &mut self.last_seen[byte as usize];
+        self.start = self.start.max(*seen);
+        self.position += 1;
+        *seen = self.position;
+        self.position - self.start >= self.size
+    }
+
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    // Forgets the bytes pushed so far, so the next marker can't overlap them
+    pub fn reset(&mut self) {
+        self.start = self.position;
+    }
+}
+
+// Number of bytes read when the last `size` of them are first all different
+pub fn first_marker(data: &[u8], size: usize) -> Option<usize> {
+    let mut detector = Detector::new(size);
+    data.iter().position(|b| detector.push(*b)).map(|i| i + 1)
+}
+
+// Every position where the last `size` bytes are all different
+pub fn all_markers(data: &[u8], size: usize) -> Vec<usize> {
+    let mut detector = Detector::new(size);
+    data.iter()
+        .enumerate()
+        .filter(|(_, b)| detector.push(**b))
+        .map(|(i, _)| i + 1)
+        .collect()
+}
+
+// Marker positions in a stream of any length, read a buffer at a time
+pub fn markers<R: BufRead>(reader: R, size: usize) -> Markers<R> {
+    Markers {
+        reader,
+        detector: Detector::new(size),
+    }
+}
+
+pub struct Markers<R> {
+    reader: R,
+    detector: Detector,
+}
+
+impl<R: BufRead> Iterator for Markers<R> {
+    type Item = Result<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let buf = match self.reader.fill_buf() {
+                Ok([]) => return None,
+                Ok(buf) => buf,
+                Err(e) => return Some(Err(e.into())),
+            };
+            let found = buf.iter().position(|b| self.detector.push(*b));
+            let used = found.map_or(buf.len(), |i| i + 1);
+            self.reader.consume(used);
+            if found.is_some() {
+                return Some(Ok(self.detector.position()));
+            }
+        }
+    }
+}
+
+// The first marker in a stream, reading no further than it
+pub fn first_marker_in(reader: impl BufRead, size: usize) -> Result<Option<usize>> {
+    markers(reader, size).next().transpose()
+}
+
+// A marker and the data that follows it, up to the next marker or the end of
+// the stream. Ranges are byte offsets into the stream.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub marker: Range<usize>,
+    pub payload: Range<usize>,
+}
+
+// Splits the stream at every marker of the given size. Markers don't overlap:
+// the search for the next one starts right after the previous one.
+pub fn frames(data: &[u8], size: usize) -> Vec<Frame> {
+    let mut detector = Detector::new(size);
+    let mut frames: Vec<Frame> = Vec::new();
+    for byte in data {
+        if detector.push(*byte) {
+            let end = detector.position();
+            if let Some(last) = frames.last_mut() {
+                last.payload.end = end - size;
+            }
+            frames.push(Frame {
+                marker: end - size..end,
+                payload: end..data.len(),
+            });
+            detector.reset();
+        }
+    }
+    frames
+}
+
+// Longest payload shown in the table before it gets cut short
+const PREVIEW: usize = 24;
+
+// The whole stream split into packets and into messages
+pub struct Scan<'a> {
+    data: &'a [u8],
+    pub packets: Vec<Frame>,
+    pub messages: Vec<Frame>,
+}
+
+pub fn scan(data: &[u8]) -> Scan<'_> {
+    Scan {
+        data,
+        packets: frames(data, PACKET),
+        messages: frames(data, MESSAGE),
+    }
+}
+
+impl Scan<'_> {
+    fn kinds(&self) -> [(&'static str, &[Frame]); 2] {
+        [("packet", &self.packets), ("message", &self.messages)]
+    }
+
+    fn text(&self, range: &Range<usize>) -> String {
+        String::from_utf8_lossy(&self.data[range.clone()]).into_owned()
+    }
+
+    pub fn table(&self) -> String {
+        let mut out = String::new();
+        for (n, (kind, frames)) in self.kinds().into_iter().enumerate() {
+            if n > 0 {
+                let _ = writeln!(out);
+            }
+            let before = frames.first().map_or(self.data.len(), |f| f.marker.start);
+            let _ = writeln!(
+                out,
+                "{} start-of-{kind} marker(s), {before} byte(s) before the first",
+                frames.len()
+            );
+            if frames.is_empty() {
+                continue;
+            }
+
+            let _ = writeln!(
+                out,
+                "{:>5} {:>6} {:<14} {:>6} payload",
+                "#", "at", "marker", "length"
+            );
+            for (i, frame) in frames.iter().enumerate() {
+                let mut payload = self.text(&frame.payload);
+                if payload.chars().count() > PREVIEW {
+                    payload = payload.chars().take(PREVIEW - 3).collect::<String>() + "...";
+                }
+                let row = format!(
+                    "{:>5} {:>6} {:<14} {:>6} {payload}",
+                    i + 1,
+                    frame.marker.end,
+                    self.text(&frame.marker),
+                    frame.payload.len()
+                );
+                let _ = writeln!(out, "{}", row.trim_end());
+            }
+        }
+        out
+    }
+
+    // Every frame with its payload in full
+    pub fn csv(&self) -> String {
+        let mut out = String::from("kind,index,at,marker,payload\n");
+        for (kind, frames) in self.kinds() {
+            for (i, frame) in frames.iter().enumerate() {
+                let _ = writeln!(
+                    out,
+                    "{kind},{},{},{},{}",
+                    i + 1,
+                    frame.marker.end,
+                    self.text(&frame.marker),
+                    self.text(&frame.payload)
+                );
+            }
+        }
+        out
+    }
+}
@@ -1,6 +1,6 @@
-use std::{collections::HashMap, fmt::Display, str::FromStr};
+use std::{collections::HashMap, str::FromStr};
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use nom::{
     branch::alt,
     bytes::complete::{tag, take_till, take_while},
@@ -10,6 +10,16 @@ use nom::{
     AsChar, IResult,
 };
 
+use crate::{
+    answer::Answers,
+    error::{ensure_consumed, ParseError},
+};
+
+const DAY: u8 = 7;
+const DISK_SIZE: u64 = 70000000;
+// Free space the update needs
+const NEEDED: u64 = 30000000;
+
 enum Command {
     Ls,
     Cd(String),
@@ -72,9 +82,9 @@ fn call(input: &str) -> IResult<&str, (Command, Vec<Output>)> {
     Ok((input, (cmd, outputs)))
 }
 
-fn main() -> Result<()> {
-    let input = include_str!("../../inputs/07.txt");
-    let (_, prog) = program(input)?;
+pub fn parse(input: &str) -> Result<Folder> {
+    let (rest, prog) = program(input).map_err(|e| ParseError::from_nom(DAY, input, e))?;
+    ensure_consumed(DAY, input, rest)?;
 
     let mut root = Folder::default();
     let mut cur_path: Vec<String> = Vec::new();
@@ -110,26 +120,36 @@ fn main() -> Result<()> {
         }
     }
 
-    let mut sizes = sizes(&root);
-    let total = root.size();
-    println!("{total}");
-
-    let current_free = 70000000 - total;
-    let min = 30000000;
-    let to_free = min - current_free;
+    Ok(root)
+}
 
-    sizes.sort();
+pub fn part1(root: &Folder) -> u64 {
+    sizes(root).iter().filter(|s| **s <= 100000).sum()
+}
 
-    let part2 = sizes.iter().find(|sz| **sz >= to_free).unwrap();
+pub fn part2(root: &Folder) -> Result<u64> {
+    let mut sizes = sizes(root);
+    let total = root.size();
 
-    let part1: u64 = sizes.iter().filter(|s| **s <= 100000).sum();
+    let current_free = DISK_SIZE
+        .checked_sub(total)
+        .ok_or_else(|| anyhow!("{total} bytes used on a disk of {DISK_SIZE}"))?;
+    let to_free = NEEDED.saturating_sub(current_free);
+    if to_free == 0 {
+        return Ok(0);
+    }
 
-    println!("Part 1: {part1} {}", root.part1());
-    println!("Part 2: {part2}");
+    sizes.sort();
 
-    //root.show(0);
+    sizes
+        .into_iter()
+        .find(|sz| *sz >= to_free)
+        .ok_or_else(|| anyhow!("no directory is large enough to free {to_free}"))
+}
 
-    Ok(())
+pub fn solve(input: &str) -> Result<Answers> {
+    let root = parse(input)?;
+    Answers::new(part1(&root), part2(&root))
 }
 
 // Bottom-up efficient way
@@ -148,28 +168,16 @@ fn sizes(folder: &Folder) -> Vec<u64> {
 }
 
 #[derive(Default, Debug)]
-struct Folder {
-    files: HashMap<String, u64>,
-    folders: HashMap<String, Folder>,
+pub struct Folder {
+    pub files: HashMap<String, u64>,
+    pub folders: HashMap<String, Folder>,
 }
 
 impl Folder {
-    fn size(&self) -> u64 {
+    pub fn size(&self) -> u64 {
         self.files.values().sum::<u64>() + self.folders.values().map(Folder::size).sum::<u64>()
     }
 
-    // Lazy way
-    fn part1(&self) -> u64 {
-        let own_size = self.size();
-        let size = self.folders.values().map(Folder::part1).sum::<u64>();
-
-        if own_size > 100000 {
-            size
-        } else {
-            own_size + size
-        }
-    }
-
     // fn show(&self, indent: usize) {
     //     for (name, folder) in self.folders.iter() {
     //         println!("{:indent$} {} (dir) {}", "", name, folder.size());
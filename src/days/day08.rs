@@ -0,0 +1,71 @@
+use anyhow::Result;
+
+use crate::{answer::Answers, grid::Grid};
+
+const DAY: u8 = 8;
+
+pub fn parse(input: &str) -> Result<Grid<i8>> {
+    let grid = Grid::parse(input, |ch| ch.to_digit(10).map(|d| d as i8))
+        .map_err(|e| e.to_parse_error(DAY, input, "expected a digit"))?;
+    Ok(grid)
+}
+
+pub fn part1(grid: &Grid<i8>) -> usize {
+    // The tallest tree between each tree and the edge, seen from each side
+    let t = left(&grid.transpose()).transpose();
+    let b = left(&grid.rotate_cw()).rotate_ccw();
+    let l = left(grid);
+    let r = left(&grid.rotate_cw().rotate_cw())
+        .rotate_ccw()
+        .rotate_ccw();
+
+    grid.iter()
+        .filter(|(pos, h)| **h > t[*pos] || **h > b[*pos] || **h > l[*pos] || **h > r[*pos])
+        .count()
+}
+
+pub fn part2(grid: &Grid<i8>) -> usize {
+    grid.positions()
+        .map(|pos| score(grid, pos))
+        .max()
+        .unwrap_or(0)
+}
+
+pub fn solve(input: &str) -> Result<Answers> {
+    let grid = parse(input)?;
+    Answers::new(part1(&grid), part2(&grid))
+}
+
+fn left(grid: &Grid<i8>) -> Grid<i8> {
+    let mut new = Grid::new(grid.width(), grid.height(), -1);
+
+    for (i, row) in grid.rows().enumerate() {
+        for j in 1..row.len() {
+            new[(i, j)] = new[(i, j - 1)].max(row[j - 1]);
+        }
+    }
+    new
+}
+
+fn score(grid: &Grid<i8>, (i, j): (usize, usize)) -> usize {
+    let h = grid[(i, j)];
+    let row = grid.row(i);
+
+    let t = viewing_distance(h, grid.column(j).take(i).rev());
+    let b = viewing_distance(h, grid.column(j).skip(i + 1));
+    let l = viewing_distance(h, row[..j].iter().rev());
+    let r = viewing_distance(h, row[(j + 1)..].iter());
+
+    t * b * l * r
+}
+
+fn viewing_distance<'a>(h: i8, trees: impl Iterator<Item = &'a i8>) -> usize {
+    let mut distance = 0;
+    for tree in trees {
+        distance += 1;
+        if *tree >= h {
+            break;
+        }
+    }
+    distance
+}
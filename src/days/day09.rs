@@ -2,18 +2,33 @@ use std::collections::HashSet;
 
 use anyhow::Result;
 
-fn main() -> Result<()> {
-    let input = include_str!("../../inputs/09.txt");
-    let commands: Vec<Command> = input
+use crate::{answer::Answers, error::ParseError};
+
+const DAY: u8 = 9;
+
+pub fn parse(input: &str) -> Result<Vec<Command>> {
+    input
         .lines()
         .map(|line| {
-            let mut parts = line.split(' ');
-            Command {
-                dir: parts.next().unwrap().chars().next().unwrap(),
-                dist: parts.next().unwrap().parse::<i32>().unwrap(),
+            let (dir, dist) = line.split_once(' ').ok_or_else(|| {
+                ParseError::at(DAY, input, line, "expected a direction and a distance")
+            })?;
+            if !matches!(dir, "L" | "R" | "U" | "D") {
+                return Err(ParseError::at(DAY, input, dir, "expected L, R, U or D").into());
             }
+            let dist = dist
+                .parse::<i32>()
+                .map_err(|_| ParseError::at(DAY, input, dist, "invalid distance"))?;
+
+            Ok(Command {
+                dir: dir.chars().next().unwrap_or_default(),
+                dist,
+            })
         })
-        .collect();
+        .collect()
+}
+
+pub fn part1(commands: &[Command]) -> usize {
     let mut visited = HashSet::new();
     let mut head = (0, 0);
     let mut tail = (0, 0);
@@ -34,10 +49,12 @@ fn main() -> Result<()> {
         }
     }
 
-    println!("Part 1: {}", visited.len());
+    visited.len()
+}
 
+pub fn part2(commands: &[Command]) -> usize {
     let mut visited = HashSet::new();
-    let mut knots = vec![(0i32, 0i32); 10];
+    let mut knots = [(0i32, 0i32); 10];
     for command in commands.iter() {
         // println!(" == {} {} ==", command.dir, command.dist);
         for _d in 0..command.dist {
@@ -52,18 +69,21 @@ fn main() -> Result<()> {
         }
     }
 
-    println!("Part 2: {}", visited.len());
+    visited.len()
+}
 
-    Ok(())
+pub fn solve(input: &str) -> Result<Answers> {
+    let commands = parse(input)?;
+    Answers::new(part1(&commands), part2(&commands))
 }
 
 fn valid_tail(head: (i32, i32), tail: (i32, i32)) -> bool {
     (head.0 - tail.0).abs() <= 1 && (head.1 - tail.1).abs() <= 1
 }
 
-struct Command {
-    dir: char,
-    dist: i32,
+pub struct Command {
+    pub dir: char,
+    pub dist: i32,
 }
 
 fn apply_move(pos: &mut (i32, i32), dir: char) {
@@ -0,0 +1,121 @@
+use anyhow::Result;
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_till},
+    character::complete::line_ending,
+    combinator::{eof, map_res},
+    multi::many1,
+    sequence::terminated,
+    IResult,
+};
+
+use crate::{
+    answer::Answers,
+    error::{ensure_consumed, ParseError},
+};
+
+const DAY: u8 = 10;
+
+#[derive(Debug)]
+pub enum Cmd {
+    Noop,
+    Addx(i32),
+}
+
+fn noop(input: &str) -> IResult<&str, Cmd> {
+    let (input, _) = terminated(tag("noop"), alt((line_ending, eof)))(input)?;
+
+    Ok((input, Cmd::Noop))
+}
+
+fn addx(input: &str) -> IResult<&str, Cmd> {
+    let (input, _) = tag("addx ")(input)?;
+    let (input, val) = map_res(take_till(|c| c == '\n'), str::parse)(input)?;
+    let (input, _) = alt((line_ending, eof))(input)?;
+
+    Ok((input, Cmd::Addx(val)))
+}
+
+fn program(input: &str) -> IResult<&str, Vec<Cmd>> {
+    many1(alt((noop, addx)))(input)
+}
+
+struct Prgm {
+    x: i32,
+    effects: Vec<(usize, i32)>,
+    next: usize,
+}
+
+pub fn parse(input: &str) -> Result<Vec<Cmd>> {
+    let (rest, cmds) = program(input).map_err(|e| ParseError::from_nom(DAY, input, e))?;
+    ensure_consumed(DAY, input, rest)?;
+    Ok(cmds)
+}
+
+// Value of the X register during each of the 240 cycles
+fn register(cmds: &[Cmd]) -> Vec<i32> {
+    let mut prog = Prgm {
+        x: 1,
+        effects: Vec::new(),
+        next: 0,
+    };
+
+    let mut next = 1;
+    for cmd in cmds.iter() {
+        match cmd {
+            Cmd::Noop => next += 1,
+            Cmd::Addx(val) => {
+                next += 2;
+                prog.effects.push((next, *val));
+            }
+        };
+    }
+
+    let mut values = Vec::new();
+    for i in 1..=240usize {
+        if prog.next < prog.effects.len() && prog.effects[prog.next].0 == i {
+            let val = prog.effects[prog.next].1;
+            prog.next += 1;
+            prog.x += val;
+        }
+
+        values.push(prog.x);
+    }
+
+    values
+}
+
+pub fn part1(cmds: &[Cmd]) -> i32 {
+    let mut part1 = 0;
+    for (i, x) in (1..=240usize).zip(register(cmds)) {
+        if (i as i32 - 20) % 40 == 0 {
+            part1 += i as i32 * x;
+        }
+    }
+
+    part1
+}
+
+pub fn part2(cmds: &[Cmd]) -> String {
+    let mut screen = String::new();
+    for (i, x) in (1..=240usize).zip(register(cmds)) {
+        let col = (i as i32 - 1) % 40;
+
+        if i > 1 && (i - 1) % 40 == 0 {
+            screen.push('\n');
+        }
+
+        if x == col - 1 || x == col || x == col + 1 {
+            screen.push('#');
+        } else {
+            screen.push('.');
+        }
+    }
+
+    screen
+}
+
+pub fn solve(input: &str) -> Result<Answers> {
+    let cmds = parse(input)?;
+    Answers::new(part1(&cmds), part2(&cmds))
+}
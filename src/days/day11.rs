@@ -11,9 +11,15 @@ use nom::{
     IResult,
 };
 
-#[derive(Debug)]
-struct Monkey {
-    id: usize,
+use crate::{
+    answer::Answers,
+    error::{ensure_consumed, ParseError},
+};
+
+const DAY: u8 = 11;
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
     items: Vec<u64>,
     operation: Op,
     test: u64,
@@ -22,7 +28,7 @@ struct Monkey {
     inspections: usize,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct Op {
     operator: Operator,
     lhs: Operand,
@@ -38,7 +44,7 @@ impl Op {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 enum Operator {
     Add,
     Mul,
@@ -53,7 +59,7 @@ impl Operator {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 enum Operand {
     Old,
     Val(u64),
@@ -89,9 +95,22 @@ fn op(input: &str) -> IResult<&str, Op> {
     Ok((input, Op { operator, lhs, rhs }))
 }
 
-fn monkey(input: &str) -> IResult<&str, Monkey> {
-    let (input, val) = delimited(tag("Monkey "), digit1, tag(":\n"))(input)?;
-    let id = val.parse::<usize>().unwrap();
+// The numbers in a monkey's description that only make sense together with the
+// other monkeys, kept as slices of the input to point errors at
+struct Refs<'a> {
+    id: &'a str,
+    test: &'a str,
+    true_monkey: &'a str,
+    false_monkey: &'a str,
+}
+
+fn number<T: FromStr>(text: &str) -> Result<T, nom::Err<nom::error::Error<&str>>> {
+    text.parse()
+        .map_err(|_| nom::Err::Error(nom::error::Error::new(text, nom::error::ErrorKind::Digit)))
+}
+
+fn monkey(input: &str) -> IResult<&str, (Monkey, Refs<'_>)> {
+    let (input, id) = delimited(tag("Monkey "), digit1, tag(":\n"))(input)?;
     let (input, items) = map_res(
         delimited(
             tag("  Starting items: "),
@@ -108,45 +127,68 @@ fn monkey(input: &str) -> IResult<&str, Monkey> {
 
     let (input, operation) = delimited(tag("  Operation: new = "), op, tag("\n"))(input)?;
 
-    let (input, test) = delimited(
-        tag("  Test: divisible by "),
-        map_res(digit1, FromStr::from_str),
-        tag("\n"),
-    )(input)?;
+    let (input, test) = delimited(tag("  Test: divisible by "), digit1, tag("\n"))(input)?;
 
-    let (input, true_monkey) = delimited(
-        tag("    If true: throw to monkey "),
-        map_res(digit1, FromStr::from_str),
-        tag("\n"),
-    )(input)?;
+    let (input, true_monkey) =
+        delimited(tag("    If true: throw to monkey "), digit1, tag("\n"))(input)?;
 
-    let (input, false_monkey) = delimited(
-        tag("    If false: throw to monkey "),
-        map_res(digit1, FromStr::from_str),
-        tag("\n"),
-    )(input)?;
+    let (input, false_monkey) =
+        delimited(tag("    If false: throw to monkey "), digit1, tag("\n"))(input)?;
 
     Ok((
         input,
-        Monkey {
-            id,
-            items,
-            operation,
-            test,
-            true_monkey,
-            false_monkey,
-            inspections: 0,
-        },
+        (
+            Monkey {
+                items,
+                operation,
+                test: number(test)?,
+                true_monkey: number(true_monkey)?,
+                false_monkey: number(false_monkey)?,
+                inspections: 0,
+            },
+            Refs {
+                id,
+                test,
+                true_monkey,
+                false_monkey,
+            },
+        ),
     ))
 }
 
-fn parse(input: &str) -> IResult<&str, Vec<Monkey>> {
+fn monkeys(input: &str) -> IResult<&str, Vec<(Monkey, Refs<'_>)>> {
     separated_list1(tag("\n"), monkey)(input)
 }
 
-fn main() -> Result<()> {
-    let input = include_str!("../../inputs/11.txt");
-    let (_, mut monkeys) = parse(input)?;
+pub fn parse(input: &str) -> Result<Vec<Monkey>> {
+    let (rest, monkeys) = monkeys(input).map_err(|e| ParseError::from_nom(DAY, input, e))?;
+    ensure_consumed(DAY, input, rest)?;
+
+    // Monkeys are listed in order and only throw to monkeys that exist
+    let count = monkeys.len();
+    for (i, (monkey, refs)) in monkeys.iter().enumerate() {
+        if refs.id.parse::<usize>().ok() != Some(i) {
+            return Err(ParseError::at(DAY, input, refs.id, format!("expected monkey {i}")).into());
+        }
+        if monkey.test == 0 {
+            return Err(ParseError::at(DAY, input, refs.test, "cannot divide by 0").into());
+        }
+        for (target, text) in [
+            (monkey.true_monkey, refs.true_monkey),
+            (monkey.false_monkey, refs.false_monkey),
+        ] {
+            if target >= count {
+                let message = format!("there is no monkey {target}");
+                return Err(ParseError::at(DAY, input, text, message).into());
+            }
+        }
+    }
+
+    Ok(monkeys.into_iter().map(|(monkey, _)| monkey).collect())
+}
+
+pub fn part1(monkeys: &[Monkey]) -> usize {
+    let mut monkeys = monkeys.to_vec();
     for _round in 0..20 {
         for i in 0..monkeys.len() {
             let items = monkeys[i].items.clone();
@@ -155,7 +197,7 @@ fn main() -> Result<()> {
                 monkeys[i].inspections += 1;
                 let mut new = monkeys[i].operation.compute(item);
                 new /= 3;
-                if new % monkeys[i].test == 0 {
+                if new.is_multiple_of(monkeys[i].test) {
                     let x = monkeys[i].true_monkey;
                     monkeys[x].items.push(new);
                 } else {
@@ -169,10 +211,11 @@ fn main() -> Result<()> {
     let mut inspections = monkeys.iter().map(|m| m.inspections).collect::<Vec<_>>();
     inspections.sort();
     inspections.reverse();
-    println!("Part 1: {}", inspections.iter().take(2).product::<usize>());
+    inspections.iter().take(2).product::<usize>()
+}
 
-    let input = include_str!("../../inputs/11.txt");
-    let (_, mut monkeys) = parse(input)?;
+pub fn part2(monkeys: &[Monkey]) -> usize {
+    let mut monkeys = monkeys.to_vec();
     let m = monkeys.iter().map(|m| m.test).product::<u64>();
     for _round in 0..10000 {
         for i in 0..monkeys.len() {
@@ -182,7 +225,7 @@ fn main() -> Result<()> {
                 monkeys[i].inspections += 1;
                 let mut new = monkeys[i].operation.compute(item);
                 new %= m;
-                if new % monkeys[i].test == 0 {
+                if new.is_multiple_of(monkeys[i].test) {
                     let x = monkeys[i].true_monkey;
                     monkeys[x].items.push(new);
                 } else {
@@ -196,6 +239,10 @@ fn main() -> Result<()> {
     let mut inspections = monkeys.iter().map(|m| m.inspections).collect::<Vec<_>>();
     inspections.sort();
     inspections.reverse();
-    println!("Part 1: {}", inspections.iter().take(2).product::<usize>());
-    Ok(())
+    inspections.iter().take(2).product::<usize>()
+}
+
+pub fn solve(input: &str) -> Result<Answers> {
+    let monkeys = parse(input)?;
+    Answers::new(part1(&monkeys), part2(&monkeys))
 }
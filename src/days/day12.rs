@@ -0,0 +1,70 @@
+use anyhow::{anyhow, Result};
+
+use crate::{
+    answer::Answers,
+    grid::{Grid, Pos},
+    search::astar,
+};
+
+const DAY: u8 = 12;
+
+pub fn parse(input: &str) -> Result<Grid<char>> {
+    let grid = Grid::parse(input, |ch| {
+        matches!(ch, 'a'..='z' | 'S' | 'E').then_some(ch)
+    })
+    .map_err(|e| e.to_parse_error(DAY, input, "expected an elevation"))?;
+    Ok(grid)
+}
+
+pub fn part1(grid: &Grid<char>) -> Result<usize> {
+    let start = grid
+        .position(|c| *c == 'S')
+        .ok_or_else(|| anyhow!("no start position"))?;
+    let end = grid
+        .position(|c| *c == 'E')
+        .ok_or_else(|| anyhow!("no end position"))?;
+
+    climb(grid, [start], end)
+}
+
+pub fn part2(grid: &Grid<char>) -> Result<usize> {
+    let end = grid
+        .position(|c| *c == 'E')
+        .ok_or_else(|| anyhow!("no end position"))?;
+    let starts = grid
+        .iter()
+        .filter(|(_, c)| mapped_char(**c) == 'a')
+        .map(|(pos, _)| pos);
+
+    climb(grid, starts, end)
+}
+
+pub fn solve(input: &str) -> Result<Answers> {
+    let grid = parse(input)?;
+    Answers::new(part1(&grid), part2(&grid))
+}
+
+// Fewest steps from any of `starts` to `end`, climbing at most one level per step
+fn climb(grid: &Grid<char>, starts: impl IntoIterator<Item = Pos>, end: Pos) -> Result<usize> {
+    let found = astar(
+        starts,
+        |pos| {
+            let ch = mapped_char(grid[*pos]);
+            grid.neighbors4(*pos)
+                .filter(move |next| mapped_char(grid[*next]) as i32 - ch as i32 <= 1)
+                .map(|next| (next, 1))
+        },
+        |pos| pos.0.abs_diff(end.0) + pos.1.abs_diff(end.1),
+        |pos| *pos == end,
+    );
+
+    Ok(found.ok_or_else(|| anyhow!("no path to the end"))?.cost)
+}
+
+fn mapped_char(ch: char) -> char {
+    match ch {
+        'E' => 'z',
+        'S' => 'a',
+        _ => ch,
+    }
+}
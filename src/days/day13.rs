@@ -1,7 +1,12 @@
-use std::{cmp::Ordering, ops::Index};
-
 use anyhow::Result;
 
+use crate::{
+    answer::Answers,
+    error::{ensure_consumed, ParseError},
+};
+
+const DAY: u8 = 13;
+
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum L {
     List(Vec<L>),
@@ -15,60 +20,17 @@ impl L {
             L::Val(_) => L::List(vec![self.clone()]),
         }
     }
-
-    fn val(&self) -> u32 {
-        match self {
-            L::List(_) => todo!(),
-            L::Val(v) => *v,
-        }
-    }
-
-    fn list(&self) -> &[L] {
-        match self {
-            L::List(l) => l,
-            L::Val(_) => todo!(),
-        }
-    }
-
-    fn is_val(&self) -> bool {
-        matches!(self, L::Val(_))
-    }
-
-    fn is_list(&self) -> bool {
-        matches!(self, L::List(_))
-    }
 }
 
 impl Ord for L {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        if self.is_val() && other.is_val() {
-            return self.val().cmp(&other.val());
+        match (self, other) {
+            (L::Val(a), L::Val(b)) => a.cmp(b),
+            // Element by element, then the shorter list first
+            (L::List(a), L::List(b)) => a.iter().cmp(b.iter()),
+            (L::Val(_), L::List(_)) => self.as_list().cmp(other),
+            (L::List(_), L::Val(_)) => self.cmp(&other.as_list()),
         }
-
-        let other = if self.is_list() {
-            other.as_list()
-        } else {
-            other.clone()
-        };
-
-        let this = if other.is_list() {
-            self.as_list()
-        } else {
-            self.clone()
-        };
-
-        let other_list = other.list();
-        let this_list = this.list();
-
-        for (a, b) in this_list.iter().zip(other_list.iter()) {
-            match a.cmp(b) {
-                x @ Ordering::Less => return x,
-                Ordering::Equal => {}
-                x @ Ordering::Greater => return x,
-            };
-        }
-
-        this_list.len().cmp(&other_list.len())
     }
 }
 
@@ -117,22 +79,25 @@ mod parser {
     }
 }
 
-fn main() -> Result<()> {
-    let input = include_str!("../../inputs/13.txt");
-    let (_input, lists) = parser::parse(input)?;
+pub fn parse(input: &str) -> Result<Vec<(L, L)>> {
+    let (rest, lists) = parser::parse(input).map_err(|e| ParseError::from_nom(DAY, input, e))?;
+    ensure_consumed(DAY, input, rest)?;
+    Ok(lists)
+}
 
-    let part1 = lists
+pub fn part1(lists: &[(L, L)]) -> usize {
+    lists
         .iter()
         .enumerate()
         .filter(|(_x, (a, b))| a <= b)
         .map(|x| x.0 + 1)
-        .sum::<usize>();
-
-    println!("Part 1: {part1}");
+        .sum::<usize>()
+}
 
+pub fn part2(lists: &[(L, L)]) -> usize {
     let mut all_lists = lists
-        .into_iter()
-        .flat_map(|(a, b)| vec![a, b])
+        .iter()
+        .flat_map(|(a, b)| vec![a.clone(), b.clone()])
         .collect::<Vec<L>>();
 
     all_lists.push(L::List(vec![L::List(vec![L::Val(2)])]));
@@ -145,7 +110,10 @@ fn main() -> Result<()> {
     let b_packet = L::List(vec![L::List(vec![L::Val(6)])]);
     let b = all_lists.iter().position(|x| x == &b_packet).unwrap() + 1;
 
-    println!("Part 2: {}", a * b);
+    a * b
+}
 
-    Ok(())
+pub fn solve(input: &str) -> Result<Answers> {
+    let lists = parse(input)?;
+    Answers::new(part1(&lists), part2(&lists))
 }
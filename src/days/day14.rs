@@ -0,0 +1,202 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+
+use crate::{
+    answer::Answers,
+    error::{ensure_consumed, ParseError},
+    grid::Grid,
+};
+
+const DAY: u8 = 14;
+
+// (x, y) as written in the scan, sand pours in at SOURCE
+pub type Path = Vec<(usize, usize)>;
+
+const SOURCE: (usize, usize) = (500, 0);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Air,
+    Rock,
+    Sand,
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let ch = match self {
+            Tile::Air => '.',
+            Tile::Rock => '#',
+            Tile::Sand => 'o',
+        };
+        write!(f, "{ch}")
+    }
+}
+
+// The slice of the cave sand can reach. Its floor is two below the lowest rock,
+// and since sand moves at most one column per row it never leaves the triangle
+// under the source, so that is as wide as the grid has to be. A deep floor
+// makes the triangle reach past x = 0, so `x_offset`, the x of the first
+// column, can be negative.
+pub struct Cave {
+    pub grid: Grid<Tile>,
+    x_offset: i64,
+    floor: usize,
+}
+
+impl Cave {
+    pub fn new(paths: &[Path]) -> Self {
+        let points = paths.iter().flat_map(points).collect::<Vec<_>>();
+        let floor = points.iter().map(|(_x, y)| *y).max().unwrap_or(0) + 2;
+
+        let reach = floor as i64 + 1;
+        let x_min = points
+            .iter()
+            .map(|(x, _y)| *x as i64)
+            .chain([SOURCE.0 as i64 - reach])
+            .min()
+            .unwrap_or(0);
+        let x_max = points
+            .iter()
+            .map(|(x, _y)| *x as i64)
+            .chain([SOURCE.0 as i64 + reach])
+            .max()
+            .unwrap_or(0);
+
+        let mut grid = Grid::new((x_max - x_min) as usize + 1, floor + 1, Tile::Air);
+        for (x, y) in points {
+            grid[(y, (x as i64 - x_min) as usize)] = Tile::Rock;
+        }
+
+        Cave {
+            grid,
+            x_offset: x_min,
+            floor,
+        }
+    }
+
+    // Drops one unit of sand from the source and returns where it came to rest,
+    // or None if it fell past the lowest rock into the abyss.
+    pub fn drop_sand(&mut self, with_floor: bool) -> Option<(i64, usize)> {
+        let mut pos = ((SOURCE.0 as i64 - self.x_offset) as usize, SOURCE.1);
+
+        loop {
+            if pos.1 == self.floor - 1 {
+                if !with_floor {
+                    return None;
+                }
+                break;
+            }
+
+            // try to go down one
+            if self.grid[(pos.1 + 1, pos.0)] == Tile::Air {
+                pos = (pos.0, pos.1 + 1);
+                continue;
+            }
+
+            // down-left
+            if self.grid[(pos.1 + 1, pos.0 - 1)] == Tile::Air {
+                pos = (pos.0 - 1, pos.1 + 1);
+                continue;
+            }
+
+            // down-right
+            if self.grid[(pos.1 + 1, pos.0 + 1)] == Tile::Air {
+                pos = (pos.0 + 1, pos.1 + 1);
+                continue;
+            }
+
+            break;
+        }
+
+        self.grid[(pos.1, pos.0)] = Tile::Sand;
+        Some((pos.0 as i64 + self.x_offset, pos.1))
+    }
+}
+
+impl Display for Cave {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.grid)
+    }
+}
+
+fn points(path: &Path) -> Vec<(usize, usize)> {
+    path.windows(2)
+        .flat_map(|path| {
+            if path[0].0 == path[1].0 {
+                let r = if path[0].1 <= path[1].1 {
+                    (path[0].1)..=(path[1].1)
+                } else {
+                    (path[1].1)..=(path[0].1)
+                };
+                r.into_iter().map(|y| (path[0].0, y)).collect::<Vec<_>>()
+            } else {
+                let r = if path[0].0 <= path[1].0 {
+                    (path[0].0)..=(path[1].0)
+                } else {
+                    (path[1].0)..=(path[0].0)
+                };
+                r.into_iter().map(|x| (x, path[0].1)).collect::<Vec<_>>()
+            }
+        })
+        .collect()
+}
+
+mod parser {
+    use std::str::FromStr;
+
+    use nom::{
+        bytes::complete::tag, character::complete::digit1, combinator::map_res,
+        multi::separated_list1, sequence::separated_pair, IResult,
+    };
+
+    use super::Path;
+
+    pub fn parse(input: &str) -> IResult<&str, Vec<Path>> {
+        separated_list1(tag("\n"), path)(input)
+    }
+
+    fn path(input: &str) -> IResult<&str, Path> {
+        separated_list1(
+            tag(" -> "),
+            separated_pair(
+                map_res(digit1, FromStr::from_str),
+                tag(","),
+                map_res(digit1, FromStr::from_str),
+            ),
+        )(input)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Path>> {
+    let (rest, paths) = parser::parse(input).map_err(|e| ParseError::from_nom(DAY, input, e))?;
+    ensure_consumed(DAY, input, rest)?;
+    Ok(paths)
+}
+
+pub fn part1(paths: &[Path]) -> usize {
+    let mut cave = Cave::new(paths);
+    let mut sands = 0;
+    while cave.drop_sand(false).is_some() {
+        sands += 1;
+    }
+    sands
+}
+
+pub fn part2(paths: &[Path]) -> usize {
+    let mut cave = Cave::new(paths);
+    let mut sands = 0;
+    while let Some(pos) = cave.drop_sand(true) {
+        sands += 1;
+
+        if pos == (SOURCE.0 as i64, SOURCE.1) {
+            break;
+        }
+    }
+    sands
+}
+
+pub fn solve(input: &str) -> Result<Answers> {
+    let paths = parse(input)?;
+    Answers::new(part1(&paths), part2(&paths))
+}
@@ -0,0 +1,79 @@
+use anyhow::Result;
+
+use crate::{
+    answer::{Answers, IntoAnswer},
+    timing::{timed, Timings},
+};
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+
+pub struct Day {
+    pub number: u8,
+    pub run: fn(&str) -> Result<Run>,
+}
+
+pub struct Run {
+    pub answers: Answers,
+    pub timings: Timings,
+}
+
+// Registers a day module, timing its `parse`, `part1` and `part2` separately.
+macro_rules! day {
+    ($number:literal, $module:ident) => {
+        Day {
+            number: $number,
+            run: |input| {
+                let (parsed, parse) = timed(|| $module::parse(input));
+                let parsed = parsed?;
+                let (part1, part1_time) = timed(|| $module::part1(&parsed));
+                let (part2, part2_time) = timed(|| $module::part2(&parsed));
+
+                Ok(Run {
+                    answers: Answers {
+                        part1: part1.into_answer()?,
+                        part2: part2.into_answer()?,
+                    },
+                    timings: Timings {
+                        parse,
+                        part1: part1_time,
+                        part2: part2_time,
+                    },
+                })
+            },
+        }
+    };
+}
+
+pub const DAYS: &[Day] = &[
+    day!(1, day01),
+    day!(2, day02),
+    day!(3, day03),
+    day!(4, day04),
+    day!(5, day05),
+    day!(6, day06),
+    day!(7, day07),
+    day!(8, day08),
+    day!(9, day09),
+    day!(10, day10),
+    day!(11, day11),
+    day!(12, day12),
+    day!(13, day13),
+    day!(14, day14),
+];
+
+pub fn get(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.number == number)
+}
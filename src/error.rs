@@ -0,0 +1,63 @@
+use std::fmt::Display;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    // Builds an error pointing at `fragment`, which must be a slice of `input`.
+    // Lines and columns are 1-based, the offending text is the rest of the line.
+    pub fn at(day: u8, input: &str, fragment: &str, message: impl Into<String>) -> Self {
+        let offset = (fragment.as_ptr() as usize)
+            .saturating_sub(input.as_ptr() as usize)
+            .min(input.len());
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+
+        ParseError {
+            day,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            text: input[offset..].lines().next().unwrap_or("").to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn from_nom(day: u8, input: &str, err: nom::Err<nom::error::Error<&str>>) -> Self {
+        match err {
+            nom::Err::Error(e) | nom::Err::Failure(e) => {
+                ParseError::at(day, input, e.input, "unexpected input")
+            }
+            nom::Err::Incomplete(_) => {
+                ParseError::at(day, input, &input[input.len()..], "unexpected end of input")
+            }
+        }
+    }
+}
+
+// Fails if anything other than whitespace is left over after parsing `input`.
+pub fn ensure_consumed(day: u8, input: &str, rest: &str) -> Result<(), ParseError> {
+    let rest = rest.trim_start();
+    if rest.is_empty() {
+        Ok(())
+    } else {
+        Err(ParseError::at(day, input, rest, "unexpected input"))
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "day {}, line {}, column {}: {}: {:?}",
+            self.day, self.line, self.column, self.message, self.text
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
@@ -0,0 +1,166 @@
+use std::fmt::Display;
+
+// A closed range of integers, both ends included
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    pub fn new(start: i64, end: i64) -> Option<Self> {
+        (start <= end).then_some(Interval { start, end })
+    }
+
+    pub fn point(x: i64) -> Self {
+        Interval { start: x, end: x }
+    }
+
+    // Intervals always hold at least one integer
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> u64 {
+        self.end.abs_diff(self.start) + 1
+    }
+
+    pub fn contains_point(&self, x: i64) -> bool {
+        self.start <= x && x <= self.end
+    }
+
+    // True when `other` lies entirely within this interval
+    pub fn contains(&self, other: &Interval) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    pub fn overlaps(&self, other: &Interval) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    pub fn intersection(&self, other: &Interval) -> Option<Interval> {
+        Interval::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    // The single interval covering both, if they overlap or touch
+    pub fn union(&self, other: &Interval) -> Option<Interval> {
+        self.gap(other).is_none().then(|| Interval {
+            start: self.start.min(other.start),
+            end: self.end.max(other.end),
+        })
+    }
+
+    // What lies strictly between the two, if anything
+    pub fn gap(&self, other: &Interval) -> Option<Interval> {
+        let (first, second) = if self.start <= other.start {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        Interval::new(first.end.checked_add(1)?, second.start.checked_sub(1)?)
+    }
+}
+
+impl Display for Interval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+// A union of intervals, kept sorted with overlapping and touching ones merged
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        IntervalSet::default()
+    }
+
+    pub fn insert(&mut self, interval: Interval) {
+        // Everything before `from` ends too early to touch `interval`, everything
+        // from `to` on starts too late
+        let from = self
+            .intervals
+            .partition_point(|i| i.end.saturating_add(1) < interval.start);
+        let to = self
+            .intervals
+            .partition_point(|i| i.start <= interval.end.saturating_add(1));
+
+        let merged = self.intervals[from..to]
+            .iter()
+            .fold(interval, |acc, i| Interval {
+                start: acc.start.min(i.start),
+                end: acc.end.max(i.end),
+            });
+        self.intervals.splice(from..to, [merged]);
+    }
+
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    // Number of integers covered
+    pub fn len(&self) -> u64 {
+        self.intervals.iter().map(Interval::len).sum()
+    }
+
+    pub fn contains_point(&self, x: i64) -> bool {
+        let i = self.intervals.partition_point(|i| i.end < x);
+        self.intervals.get(i).is_some_and(|i| i.contains_point(x))
+    }
+
+    // From the lowest covered integer to the highest
+    pub fn span(&self) -> Option<Interval> {
+        Interval::new(self.intervals.first()?.start, self.intervals.last()?.end)
+    }
+
+    // The holes between the intervals
+    pub fn gaps(&self) -> Vec<Interval> {
+        self.intervals
+            .windows(2)
+            .filter_map(|w| w[0].gap(&w[1]))
+            .collect()
+    }
+
+    // The parts of `within` that aren't covered
+    pub fn uncovered(&self, within: Interval) -> IntervalSet {
+        let mut uncovered = IntervalSet::new();
+        let mut next = Some(within.start);
+        for i in self.intervals.iter().filter(|i| i.overlaps(&within)) {
+            let before = next.zip(i.start.checked_sub(1));
+            if let Some(gap) = before.and_then(|(start, end)| Interval::new(start, end)) {
+                uncovered.insert(gap);
+            }
+            next = i.end.checked_add(1);
+        }
+        if let Some(rest) = next.and_then(|start| Interval::new(start, within.end)) {
+            uncovered.insert(rest);
+        }
+        uncovered
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<T: IntoIterator<Item = Interval>>(iter: T) -> Self {
+        let mut set = IntervalSet::new();
+        for interval in iter {
+            set.insert(interval);
+        }
+        set
+    }
+}
+
+impl Display for IntervalSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (n, i) in self.intervals.iter().enumerate() {
+            if n > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{i}")?;
+        }
+        Ok(())
+    }
+}
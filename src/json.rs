@@ -0,0 +1,40 @@
+use crate::{answer::Answer, days::Run};
+
+pub fn escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for ch in s.chars() {
+        match ch {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+pub fn answer(answer: &Answer) -> String {
+    match answer {
+        Answer::Number(n) => n.to_string(),
+        Answer::Text(s) => escape(s),
+    }
+}
+
+// One JSON object per day, with timings in nanoseconds:
+// {"day":7,"part1":95437,"part2":24933642,"timings":{"parse_ns":..,"part1_ns":..,"part2_ns":..}}
+pub fn run(day: u8, run: &Run) -> String {
+    format!(
+        r#"{{"day":{},"part1":{},"part2":{},"timings":{{"parse_ns":{},"part1_ns":{},"part2_ns":{}}}}}"#,
+        day,
+        answer(&run.answers.part1),
+        answer(&run.answers.part2),
+        run.timings.parse.as_nanos(),
+        run.timings.part1.as_nanos(),
+        run.timings.part2.as_nanos()
+    )
+}
@@ -0,0 +1,13 @@
+pub mod answer;
+pub mod days;
+pub mod error;
+pub mod grid;
+pub mod interval;
+pub mod json;
+pub mod search;
+pub mod timing;
+pub mod verify;
+
+pub use answer::{Answer, Answers};
+pub use error::ParseError;
+pub use grid::Grid;
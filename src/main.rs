@@ -1,3 +1,569 @@
-fn main() {
-    println!("Hello, world!");
+use std::{fs, io::Read};
+
+use anyhow::{anyhow, bail, Context, Result};
+
+use aoc2022::{
+    days::{
+        self,
+        day02::{analysis, game::Game, tournament},
+        day05::View,
+        Day, Run,
+    },
+    json,
+    timing::{Summary, Timings},
+    verify::{KnownAnswers, Verdict},
+    Answer,
+};
+
+const USAGE: &str = "\
+usage: aoc2022 run [all | DAY...] [--input PATH | --input -] [--format table | --format json]
+       aoc2022 verify [all | DAY...] [--answers PATH]
+       aoc2022 bench [all | DAY...] [--runs N] [--format table | --format csv]
+       aoc2022 report [all | DAY...] [--input PATH | --input -] [--format table | --format csv]
+                      [--rules PATH | --analyze | --tournament [--rounds N] [--seed N]]
+                      [--group-size N] [--crane 9000 | 9001 | flip | limit:N]...
+                      [--steps | --step N | --diff | --inverse]";
+
+#[derive(PartialEq, Eq)]
+enum Format {
+    Table,
+    Csv,
+    Json,
+}
+
+struct Options {
+    days: Vec<&'static Day>,
+    input: Option<String>,
+    answers: String,
+    rules: Option<String>,
+    analyze: bool,
+    tournament: bool,
+    rounds: usize,
+    seed: u64,
+    group_size: usize,
+    cranes: Vec<String>,
+    view: Option<View>,
+    runs: usize,
+    format: Format,
+}
+
+fn main() -> Result<()> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    match args.first().map(String::as_str) {
+        Some("run") => run(&parse_options("run", &args[1..])?),
+        Some("verify") => verify(&parse_options("verify", &args[1..])?),
+        Some("bench") => bench(&parse_options("bench", &args[1..])?),
+        Some("report") => report(&parse_options("report", &args[1..])?),
+        _ => bail!(USAGE),
+    }
+}
+
+// The flags each subcommand accepts. Anything else is an error rather than
+// being silently ignored.
+fn flags(command: &str) -> &'static [&'static str] {
+    match command {
+        "run" => &["--input", "-i", "--format"],
+        "verify" => &["--answers"],
+        "bench" => &["--runs", "--format"],
+        _ => &[
+            "--input",
+            "-i",
+            "--format",
+            "--rules",
+            "--analyze",
+            "--tournament",
+            "--rounds",
+            "--seed",
+            "--group-size",
+            "--crane",
+            "--steps",
+            "--step",
+            "--diff",
+            "--inverse",
+        ],
+    }
+}
+
+// Report flags that only mean something for one day
+const DAY_FLAGS: &[(&str, u8)] = &[
+    ("--rules", 2),
+    ("--analyze", 2),
+    ("--tournament", 2),
+    ("--rounds", 2),
+    ("--seed", 2),
+    ("--group-size", 3),
+    ("--crane", 5),
+    ("--steps", 5),
+    ("--step", 5),
+    ("--diff", 5),
+    ("--inverse", 5),
+];
+
+fn parse_options(command: &str, args: &[String]) -> Result<Options> {
+    let mut selected = Vec::new();
+    let mut input = None;
+    let mut answers = "answers.toml".to_string();
+    let mut rules = None;
+    let mut analyze = false;
+    let mut tournament = false;
+    let mut rounds = 1000;
+    let mut seed = 2022;
+    let mut group_size = days::day03::GROUP_SIZE;
+    let mut cranes = Vec::new();
+    let mut views = Vec::new();
+    let mut runs = 10;
+    let mut format = Format::Table;
+
+    let mut seen = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if arg.starts_with('-') {
+            if !flags(command).contains(&arg.as_str()) {
+                bail!("{arg} is not supported by {command}\n{USAGE}");
+            }
+            seen.push(arg.as_str());
+        }
+        match arg.as_str() {
+            "--input" | "-i" => {
+                let path = args
+                    .next()
+                    .ok_or_else(|| anyhow!("--input requires a path\n{USAGE}"))?;
+                input = Some(path.clone());
+            }
+            "--answers" => {
+                let path = args
+                    .next()
+                    .ok_or_else(|| anyhow!("--answers requires a path\n{USAGE}"))?;
+                answers = path.clone();
+            }
+            "--rules" => {
+                let path = args
+                    .next()
+                    .ok_or_else(|| anyhow!("--rules requires a path\n{USAGE}"))?;
+                rules = Some(path.clone());
+            }
+            "--analyze" => analyze = true,
+            "--tournament" => tournament = true,
+            "--rounds" => {
+                rounds = args
+                    .next()
+                    .and_then(|n| n.parse::<usize>().ok())
+                    .filter(|n| *n > 0)
+                    .ok_or_else(|| anyhow!("--rounds requires a positive number\n{USAGE}"))?;
+            }
+            "--group-size" => {
+                group_size = args
+                    .next()
+                    .and_then(|n| n.parse::<usize>().ok())
+                    .filter(|n| *n > 0)
+                    .ok_or_else(|| anyhow!("--group-size requires a positive number\n{USAGE}"))?;
+            }
+            "--crane" => {
+                let name = args
+                    .next()
+                    .filter(|n| days::day05::crane(n).is_some())
+                    .ok_or_else(|| {
+                        anyhow!("--crane must be 9000, 9001, flip or limit:N\n{USAGE}")
+                    })?;
+                cranes.push(name.clone());
+            }
+            "--steps" => views.push(View::Steps),
+            "--step" => {
+                let n = args
+                    .next()
+                    .and_then(|n| n.parse::<usize>().ok())
+                    .ok_or_else(|| anyhow!("--step requires a number\n{USAGE}"))?;
+                views.push(View::Step(n));
+            }
+            "--diff" => views.push(View::Diff),
+            "--inverse" => views.push(View::Inverse),
+            "--seed" => {
+                seed = args
+                    .next()
+                    .and_then(|n| n.parse::<u64>().ok())
+                    .ok_or_else(|| anyhow!("--seed requires a number\n{USAGE}"))?;
+            }
+            "--runs" => {
+                runs = args
+                    .next()
+                    .and_then(|n| n.parse::<usize>().ok())
+                    .filter(|n| *n > 0)
+                    .ok_or_else(|| anyhow!("--runs requires a positive number\n{USAGE}"))?;
+            }
+            "--format" => {
+                format = match args.next().map(String::as_str) {
+                    Some("table") => Format::Table,
+                    Some("csv") => Format::Csv,
+                    Some("json") => Format::Json,
+                    _ => bail!("--format must be table, csv or json\n{USAGE}"),
+                };
+            }
+            "all" => selected.extend(days::DAYS.iter()),
+            _ => {
+                let number = arg
+                    .parse::<u8>()
+                    .map_err(|_| anyhow!("invalid day: {arg}\n{USAGE}"))?;
+                let day =
+                    days::get(number).ok_or_else(|| anyhow!("day {number} is not implemented"))?;
+                selected.push(day);
+            }
+        }
+    }
+
+    // The views follow a single crane, the report compares them
+    if cranes.is_empty() {
+        cranes = if views.is_empty() {
+            vec!["9000".to_string(), "9001".to_string()]
+        } else {
+            vec!["9000".to_string()]
+        };
+    }
+
+    if selected.is_empty() {
+        selected.extend(days::DAYS.iter());
+    }
+
+    for &(flag, number) in DAY_FLAGS {
+        if seen.contains(&flag) && !selected.iter().any(|d| d.number == number) {
+            bail!("{flag} only applies to day {number}");
+        }
+    }
+
+    if !tournament && (seen.contains(&"--rounds") || seen.contains(&"--seed")) {
+        bail!("--rounds and --seed require --tournament");
+    }
+
+    if input.is_some() && selected.len() > 1 {
+        bail!("--input can only be used when running a single day");
+    }
+
+    if [rules.is_some(), analyze, tournament]
+        .iter()
+        .filter(|b| **b)
+        .count()
+        > 1
+    {
+        bail!("--rules, --analyze and --tournament cannot be combined");
+    }
+
+    if views.len() > 1 {
+        bail!("--steps, --step, --diff and --inverse cannot be combined");
+    }
+
+    if !views.is_empty() && cranes.len() > 1 {
+        bail!("--steps, --step, --diff and --inverse follow a single --crane");
+    }
+
+    Ok(Options {
+        days: selected,
+        input,
+        answers,
+        rules,
+        analyze,
+        tournament,
+        rounds,
+        seed,
+        group_size,
+        cranes,
+        view: views.pop(),
+        runs,
+        format,
+    })
+}
+
+fn load_input(day: &Day, path: Option<&str>) -> Result<String> {
+    match path {
+        Some("-") => {
+            let mut input = String::new();
+            std::io::stdin()
+                .read_to_string(&mut input)
+                .with_context(|| {
+                    format!("failed to read input for day {} from stdin", day.number)
+                })?;
+            Ok(input)
+        }
+        Some(path) => read_input(day, path),
+        None => read_input(day, &format!("inputs/{:02}.txt", day.number)),
+    }
+}
+
+fn read_input(day: &Day, path: &str) -> Result<String> {
+    fs::read_to_string(path)
+        .with_context(|| format!("failed to read input for day {} from {path}", day.number))
+}
+
+fn run_day(day: &Day, input: &str) -> Result<Run> {
+    (day.run)(input).with_context(|| format!("day {} failed", day.number))
+}
+
+fn run(options: &Options) -> Result<()> {
+    if options.format == Format::Csv {
+        bail!("--format csv is only supported by bench and report");
+    }
+
+    for day in options.days.iter() {
+        let input = load_input(day, options.input.as_deref())?;
+        let run = run_day(day, &input)?;
+
+        if options.format == Format::Json {
+            println!("{}", json::run(day.number, &run));
+            continue;
+        }
+
+        let Run { answers, timings } = run;
+
+        println!("Day {:02}", day.number);
+        print_part(1, &answers.part1);
+        print_part(2, &answers.part2);
+        println!(
+            "Time: parse {:.1?}, part 1 {:.1?}, part 2 {:.1?}",
+            timings.parse, timings.part1, timings.part2
+        );
+    }
+
+    Ok(())
+}
+
+fn bench(options: &Options) -> Result<()> {
+    if options.format == Format::Json {
+        bail!("--format json is only supported by run");
+    }
+
+    if options.format == Format::Csv {
+        println!("day,stage,runs,min_ns,median_ns,max_ns");
+    } else {
+        println!(
+            "{:<4} {:<6} {:>5} {:>12} {:>12} {:>12}",
+            "day", "stage", "runs", "min", "median", "max"
+        );
+    }
+
+    for day in options.days.iter() {
+        let input = load_input(day, options.input.as_deref())?;
+        let samples = (0..options.runs)
+            .map(|_| run_day(day, &input).map(|run| run.timings))
+            .collect::<Result<Vec<Timings>>>()?;
+
+        let Some(first) = samples.first() else {
+            continue;
+        };
+
+        for (i, (stage, _)) in first.stages().into_iter().enumerate() {
+            let durations = samples.iter().map(|t| t.stages()[i].1).collect::<Vec<_>>();
+            let Some(summary) = Summary::of(&durations) else {
+                continue;
+            };
+
+            if options.format == Format::Csv {
+                println!(
+                    "{},{stage},{},{},{},{}",
+                    day.number,
+                    options.runs,
+                    summary.min.as_nanos(),
+                    summary.median.as_nanos(),
+                    summary.max.as_nanos()
+                );
+            } else {
+                println!(
+                    "{:<4} {:<6} {:>5} {:>12} {:>12} {:>12}",
+                    format!("{:02}", day.number),
+                    stage,
+                    options.runs,
+                    format!("{:.1?}", summary.min),
+                    format!("{:.1?}", summary.median),
+                    format!("{:.1?}", summary.max)
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn verify(options: &Options) -> Result<()> {
+    if options.input.is_some() {
+        bail!("--input cannot be used with verify, known answers only apply to inputs/NN.txt");
+    }
+    if options.format != Format::Table {
+        bail!("verify only supports --format table");
+    }
+
+    let known = fs::read_to_string(&options.answers)
+        .with_context(|| format!("failed to read answers from {}", options.answers))?;
+    let known = KnownAnswers::parse(&known)
+        .with_context(|| format!("invalid answers file {}", options.answers))?;
+
+    // A day that fails to load or run counts as a failure for both parts, the
+    // other days are still checked
+    let mut failures = 0;
+    for day in options.days.iter() {
+        println!("Day {:02}", day.number);
+        let answers = match load_input(day, None).and_then(|input| run_day(day, &input)) {
+            Ok(run) => run.answers,
+            Err(e) => {
+                println!("Part 1: FAIL\nPart 2: FAIL\nerror: {e:#}");
+                failures += 2;
+                continue;
+            }
+        };
+
+        for (part, answer) in [(1, &answers.part1), (2, &answers.part2)] {
+            let verdict = known.check(day.number, part, answer);
+            if matches!(verdict, Verdict::Fail { .. }) {
+                failures += 1;
+            }
+            print_verdict(part, answer, &verdict);
+        }
+    }
+
+    if failures > 0 {
+        bail!("{failures} answer(s) do not match {}", options.answers);
+    }
+
+    Ok(())
+}
+
+// Days with more to say than their two answers. Days without a report are
+// skipped, unless none of the selected days have one.
+fn report(options: &Options) -> Result<()> {
+    if options.format == Format::Json {
+        bail!("--format json is only supported by run");
+    }
+
+    let mut reported = 0;
+    for day in options.days.iter() {
+        let csv = options.format == Format::Csv;
+        let out = match day.number {
+            1 => {
+                let input = load_input(day, options.input.as_deref())?;
+                let report = days::day01::report(&input)?;
+                if csv {
+                    report.csv()
+                } else {
+                    report.table()
+                }
+            }
+            2 => {
+                let input = load_input(day, options.input.as_deref())?;
+                if options.tournament {
+                    let matches = days::day02::parse(&input)?;
+                    let mut entrants = tournament::entrants(&matches, options.seed);
+                    let leaderboard = tournament::round_robin(&mut entrants, options.rounds);
+                    if csv {
+                        leaderboard.csv()
+                    } else {
+                        leaderboard.table()
+                    }
+                } else if options.analyze {
+                    let analysis = analysis::analyze(&days::day02::parse(&input)?);
+                    if csv {
+                        analysis.csv()
+                    } else {
+                        analysis.table()
+                    }
+                } else {
+                    let report = match options.rules.as_deref() {
+                        Some(path) => {
+                            let rules = fs::read_to_string(path)
+                                .with_context(|| format!("failed to read rules from {path}"))?;
+                            let game = Game::parse(&rules)
+                                .with_context(|| format!("invalid rules file {path}"))?;
+                            days::day02::report(&input, path, &game)?
+                        }
+                        None => days::day02::report(&input, "standard", &Game::standard())?,
+                    };
+                    if csv {
+                        report.csv()
+                    } else {
+                        report.table()
+                    }
+                }
+            }
+            3 => {
+                let input = load_input(day, options.input.as_deref())?;
+                let report = days::day03::report(&input, options.group_size)?;
+                if csv {
+                    report.csv()
+                } else {
+                    report.table()
+                }
+            }
+            4 => {
+                let input = load_input(day, options.input.as_deref())?;
+                let report = days::day04::report(&input)?;
+                if csv {
+                    report.csv()
+                } else {
+                    report.table()
+                }
+            }
+            5 => {
+                let input = load_input(day, options.input.as_deref())?;
+                if let Some(view) = options.view {
+                    if csv {
+                        bail!("--steps, --step, --diff and --inverse only support --format table");
+                    }
+                    let crane = days::day05::crane(&options.cranes[0])
+                        .ok_or_else(|| anyhow!("unknown crane {}", options.cranes[0]))?;
+                    days::day05::watch(&input, crane.as_ref(), view)?
+                } else {
+                    let report = days::day05::report(&input, &options.cranes)?;
+                    if csv {
+                        report.csv()
+                    } else {
+                        report.table()
+                    }
+                }
+            }
+            6 => {
+                let input = load_input(day, options.input.as_deref())?;
+                let data = days::day06::parse(&input)?;
+                let scan = days::day06::scan(&data);
+                if csv {
+                    scan.csv()
+                } else {
+                    scan.table()
+                }
+            }
+            _ => continue,
+        };
+
+        if !csv {
+            println!("Day {:02}", day.number);
+        }
+        print!("{out}");
+        reported += 1;
+    }
+
+    if reported == 0 {
+        bail!("none of the selected days have a report");
+    }
+
+    Ok(())
+}
+
+fn print_verdict(part: u8, answer: &Answer, verdict: &Verdict) {
+    match verdict {
+        Verdict::Fail { expected } if is_multiline(answer) || is_multiline(expected) => {
+            println!("Part {part}: {verdict}\n{answer}\nexpected:\n{expected}")
+        }
+        Verdict::Fail { expected } => {
+            println!("Part {part}: {verdict} {answer} (expected {expected})")
+        }
+        _ if is_multiline(answer) => println!("Part {part}: {verdict}\n{answer}"),
+        _ => println!("Part {part}: {verdict} {answer}"),
+    }
+}
+
+fn is_multiline(answer: &Answer) -> bool {
+    matches!(answer, Answer::Text(text) if text.contains('\n'))
+}
+
+fn print_part(part: u8, answer: &Answer) {
+    if is_multiline(answer) {
+        println!("Part {part}:\n{answer}");
+    } else {
+        println!("Part {part}: {answer}");
+    }
 }
@@ -0,0 +1,170 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Found<N, C> {
+    pub cost: C,
+    // From the start that was reached first to the goal, both included
+    pub path: Vec<N>,
+}
+
+// Nodes seen so far, stored once and referred to by index so the queues and
+// parent pointers don't need to clone them.
+struct Nodes<N> {
+    index: HashMap<N, usize>,
+    nodes: Vec<N>,
+    parents: Vec<Option<usize>>,
+}
+
+impl<N: Clone + Hash + Eq> Nodes<N> {
+    fn new() -> Self {
+        Nodes {
+            index: HashMap::new(),
+            nodes: Vec::new(),
+            parents: Vec::new(),
+        }
+    }
+
+    // Returns the index of `node` and whether it was seen for the first time
+    fn insert(&mut self, node: N, parent: Option<usize>) -> (usize, bool) {
+        if let Some(i) = self.index.get(&node) {
+            return (*i, false);
+        }
+
+        let i = self.nodes.len();
+        self.index.insert(node.clone(), i);
+        self.nodes.push(node);
+        self.parents.push(parent);
+        (i, true)
+    }
+
+    fn path(&self, mut i: usize) -> Vec<N> {
+        let mut path = vec![self.nodes[i].clone()];
+        while let Some(parent) = self.parents[i] {
+            path.push(self.nodes[parent].clone());
+            i = parent;
+        }
+        path.reverse();
+        path
+    }
+}
+
+// Breadth-first search where every edge costs 1.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Found<N, usize>>
+where
+    N: Clone + Hash + Eq,
+    I: IntoIterator<Item = N>,
+{
+    let mut nodes = Nodes::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if let (i, true) = nodes.insert(start, None) {
+            queue.push_back((i, 0));
+        }
+    }
+
+    while let Some((i, cost)) = queue.pop_front() {
+        if is_goal(&nodes.nodes[i]) {
+            return Some(Found {
+                cost,
+                path: nodes.path(i),
+            });
+        }
+
+        for next in neighbors(&nodes.nodes[i]) {
+            if let (j, true) = nodes.insert(next, Some(i)) {
+                queue.push_back((j, cost + 1));
+            }
+        }
+    }
+
+    None
+}
+
+// Shortest path where `neighbors` yields each neighbor with the cost of reaching it.
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Found<N, C>>
+where
+    N: Clone + Hash + Eq,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(starts, neighbors, |_| C::default(), is_goal)
+}
+
+// Dijkstra guided by `heuristic`, which must never overestimate the remaining
+// cost to a goal for the result to be the shortest path. A node that was already
+// expanded is opened again when a cheaper way to it turns up, which only happens
+// when the heuristic isn't consistent.
+pub fn astar<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Found<N, C>>
+where
+    N: Clone + Hash + Eq,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut nodes = Nodes::new();
+    let mut costs = Vec::new();
+    let mut done = Vec::new();
+    let mut heap = BinaryHeap::new();
+
+    for start in starts {
+        if let (i, true) = nodes.insert(start, None) {
+            costs.push(C::default());
+            done.push(false);
+            heap.push(Reverse((heuristic(&nodes.nodes[i]), C::default(), i)));
+        }
+    }
+
+    while let Some(Reverse((_, cost, i))) = heap.pop() {
+        if done[i] || cost > costs[i] {
+            continue;
+        }
+        done[i] = true;
+
+        if is_goal(&nodes.nodes[i]) {
+            return Some(Found {
+                cost,
+                path: nodes.path(i),
+            });
+        }
+
+        for (next, step) in neighbors(&nodes.nodes[i]) {
+            let next_cost = cost + step;
+            let (j, new) = nodes.insert(next, Some(i));
+            if new {
+                costs.push(next_cost);
+                done.push(false);
+            } else if next_cost >= costs[j] {
+                continue;
+            } else {
+                costs[j] = next_cost;
+                nodes.parents[j] = Some(i);
+                done[j] = false;
+            }
+            heap.push(Reverse((
+                next_cost + heuristic(&nodes.nodes[j]),
+                next_cost,
+                j,
+            )));
+        }
+    }
+
+    None
+}
@@ -0,0 +1,129 @@
+use std::{collections::HashMap, fmt::Display};
+
+use anyhow::{anyhow, bail, Result};
+
+use crate::answer::Answer;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: Answer },
+    Unknown,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "PASS"),
+            Verdict::Fail { .. } => write!(f, "FAIL"),
+            Verdict::Unknown => write!(f, "UNKNOWN"),
+        }
+    }
+}
+
+// Accepted answers keyed by day and part, as stored in `answers.toml`. Only the
+// subset of TOML this file needs is supported: `[dayNN]` sections with `part1`
+// and `part2` keys holding integers, strings or multi-line strings.
+#[derive(Debug, Default)]
+pub struct KnownAnswers {
+    answers: HashMap<(u8, u8), Answer>,
+}
+
+impl KnownAnswers {
+    pub fn parse(input: &str) -> Result<Self> {
+        let mut answers = HashMap::new();
+        let mut day = None;
+
+        let mut lines = input.lines().enumerate().map(|(i, l)| (i + 1, l));
+        while let Some((line_no, line)) = lines.next() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(section) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                let number = section
+                    .strip_prefix("day")
+                    .and_then(|n| n.parse::<u8>().ok())
+                    .ok_or_else(|| anyhow!("line {line_no}: expected [dayNN], got [{section}]"))?;
+                day = Some(number);
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| anyhow!("line {line_no}: expected `key = value`"))?;
+            let day =
+                day.ok_or_else(|| anyhow!("line {line_no}: key outside of a [dayNN] section"))?;
+            let part = match key.trim() {
+                "part1" => 1,
+                "part2" => 2,
+                other => bail!("line {line_no}: unknown key {other}, expected part1 or part2"),
+            };
+
+            let value = value.trim();
+            let answer = if let Some(rest) = value.strip_prefix(r#"""""#) {
+                let mut chunks = Vec::new();
+                let mut current = rest;
+                loop {
+                    if let Some(end) = current.find(r#"""""#) {
+                        chunks.push(&current[..end]);
+                        break;
+                    }
+                    chunks.push(current);
+                    current = lines
+                        .next()
+                        .ok_or_else(|| anyhow!("line {line_no}: unterminated multi-line string"))?
+                        .1;
+                }
+                let text = chunks.join("\n");
+                // A newline right after the opening delimiter is not part of the string
+                Answer::Text(text.strip_prefix('\n').unwrap_or(&text).to_string())
+            } else if let Some(text) = value.strip_prefix('"').and_then(|v| v.strip_suffix('"')) {
+                Answer::Text(unescape(text))
+            } else {
+                let number = value
+                    .replace('_', "")
+                    .parse::<i64>()
+                    .map_err(|_| anyhow!("line {line_no}: invalid value {value}"))?;
+                Answer::Number(number)
+            };
+
+            if answers.insert((day, part), answer).is_some() {
+                bail!("line {line_no}: duplicate answer for day {day} part {part}");
+            }
+        }
+
+        Ok(KnownAnswers { answers })
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&Answer> {
+        self.answers.get(&(day, part))
+    }
+
+    pub fn check(&self, day: u8, part: u8, answer: &Answer) -> Verdict {
+        match self.get(day, part) {
+            None => Verdict::Unknown,
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.clone(),
+            },
+        }
+    }
+}
+
+fn unescape(s: &str) -> String {
+    let mut out = String::new();
+    let mut chars = s.chars();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\\' => match chars.next() {
+                Some('n') => out.push('\n'),
+                Some(other) => out.push(other),
+                None => out.push('\\'),
+            },
+            _ => out.push(ch),
+        }
+    }
+    out
+}
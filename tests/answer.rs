@@ -0,0 +1,17 @@
+use aoc2022::{answer::IntoAnswer, Answer, Answers};
+
+#[test]
+fn conversions() {
+    assert_eq!(Answer::try_from(42u64).unwrap(), Answer::Number(42));
+    assert_eq!(
+        (i64::MAX as usize).into_answer().unwrap(),
+        Answer::Number(i64::MAX)
+    );
+    assert!(Answer::try_from(u64::MAX).is_err());
+    assert!(usize::MAX.into_answer().is_err());
+    assert!(anyhow::Ok(1u64 << 63).into_answer().is_err());
+
+    let answers = Answers::new(7u32, "CMZ".to_string()).unwrap();
+    assert_eq!(answers.part1, Answer::Number(7));
+    assert!(Answers::new(1, u64::MAX).is_err());
+}
@@ -0,0 +1,147 @@
+use aoc2022::{
+    days::*,
+    verify::{KnownAnswers, Verdict},
+    Answers,
+};
+
+type Solve = fn(&str) -> anyhow::Result<Answers>;
+
+const SCREEN: &str = "\
+####..##....##..##..###....##.###..####.\n\
+#....#..#....#.#..#.#..#....#.#..#.#....\n\
+###..#.......#.#..#.#..#....#.#..#.###..\n\
+#....#.......#.####.###.....#.###..#....\n\
+#....#..#.#..#.#..#.#....#..#.#.#..#....\n\
+#.....##...##..#..#.#.....##..#..#.####.";
+
+#[test]
+fn day01() {
+    let input = day01::parse(include_str!("../inputs/01.txt")).unwrap();
+    assert_eq!(day01::part1(&input), 71934);
+    assert_eq!(day01::part2(&input), 211447);
+}
+
+#[test]
+fn day02() {
+    let input = day02::parse(include_str!("../inputs/02.txt")).unwrap();
+    assert_eq!(day02::part1(&input), 11449);
+    assert_eq!(day02::part2(&input), 13187);
+}
+
+#[test]
+fn day03() {
+    let input = day03::parse(include_str!("../inputs/03.txt")).unwrap();
+    assert_eq!(day03::part1(&input).unwrap(), 7737);
+    assert_eq!(day03::part2(&input).unwrap(), 2697);
+}
+
+#[test]
+fn day04() {
+    let input = day04::parse(include_str!("../inputs/04.txt")).unwrap();
+    assert_eq!(day04::part1(&input), 500);
+    assert_eq!(day04::part2(&input), 815);
+}
+
+#[test]
+fn day05() {
+    let input = day05::parse(include_str!("../inputs/05.txt")).unwrap();
+    assert_eq!(day05::part1(&input).unwrap(), "PSNRGBTFT");
+    assert_eq!(day05::part2(&input).unwrap(), "BNTZFPMMW");
+}
+
+#[test]
+fn day06() {
+    let input = day06::parse(include_str!("../inputs/06.txt")).unwrap();
+    assert_eq!(day06::part1(&input).unwrap(), 1262);
+    assert_eq!(day06::part2(&input).unwrap(), 3444);
+}
+
+#[test]
+fn day07() {
+    let input = day07::parse(include_str!("../inputs/07.txt")).unwrap();
+    assert_eq!(day07::part1(&input), 1749646);
+    assert_eq!(day07::part2(&input).unwrap(), 1498966);
+}
+
+#[test]
+fn day08() {
+    let input = day08::parse(include_str!("../inputs/08.txt")).unwrap();
+    assert_eq!(day08::part1(&input), 1794);
+    assert_eq!(day08::part2(&input), 199272);
+}
+
+#[test]
+fn day09() {
+    let input = day09::parse(include_str!("../inputs/09.txt")).unwrap();
+    assert_eq!(day09::part1(&input), 6391);
+    assert_eq!(day09::part2(&input), 2593);
+}
+
+#[test]
+fn day10() {
+    let input = day10::parse(include_str!("../inputs/10.txt")).unwrap();
+    assert_eq!(day10::part1(&input), 12880);
+    assert_eq!(day10::part2(&input), SCREEN);
+}
+
+#[test]
+fn day11() {
+    let input = day11::parse(include_str!("../inputs/11.txt")).unwrap();
+    assert_eq!(day11::part1(&input), 316888);
+    assert_eq!(day11::part2(&input), 35270398814);
+}
+
+#[test]
+fn day12() {
+    let input = day12::parse(include_str!("../inputs/12.txt")).unwrap();
+    assert_eq!(day12::part1(&input).unwrap(), 383);
+    assert_eq!(day12::part2(&input).unwrap(), 377);
+}
+
+#[test]
+fn day13() {
+    let input = day13::parse(include_str!("../inputs/13.txt")).unwrap();
+    assert_eq!(day13::part1(&input), 6101);
+    assert_eq!(day13::part2(&input), 21909);
+}
+
+#[test]
+fn day14() {
+    let input = day14::parse(include_str!("../inputs/14.txt")).unwrap();
+    assert_eq!(day14::part1(&input), 1003);
+    assert_eq!(day14::part2(&input), 25771);
+}
+
+// `solve` is what other tools call, so it must agree with the runner's answers
+#[test]
+fn solve_every_day() {
+    let solvers: [(u8, Solve, &str); 14] = [
+        (1, day01::solve, include_str!("../inputs/01.txt")),
+        (2, day02::solve, include_str!("../inputs/02.txt")),
+        (3, day03::solve, include_str!("../inputs/03.txt")),
+        (4, day04::solve, include_str!("../inputs/04.txt")),
+        (5, day05::solve, include_str!("../inputs/05.txt")),
+        (6, day06::solve, include_str!("../inputs/06.txt")),
+        (7, day07::solve, include_str!("../inputs/07.txt")),
+        (8, day08::solve, include_str!("../inputs/08.txt")),
+        (9, day09::solve, include_str!("../inputs/09.txt")),
+        (10, day10::solve, include_str!("../inputs/10.txt")),
+        (11, day11::solve, include_str!("../inputs/11.txt")),
+        (12, day12::solve, include_str!("../inputs/12.txt")),
+        (13, day13::solve, include_str!("../inputs/13.txt")),
+        (14, day14::solve, include_str!("../inputs/14.txt")),
+    ];
+    assert_eq!(solvers.len(), DAYS.len());
+
+    let known = KnownAnswers::parse(include_str!("../answers.toml")).unwrap();
+    for (day, solve, input) in solvers {
+        let answers = solve(input).unwrap();
+        for (part, answer) in [(1, &answers.part1), (2, &answers.part2)] {
+            assert_eq!(
+                known.check(day, part, answer),
+                Verdict::Pass,
+                "day {day} part {part}"
+            );
+        }
+    }
+}
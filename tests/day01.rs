@@ -0,0 +1,122 @@
+use std::io::{BufReader, Read};
+
+use aoc2022::{days::day01, ParseError};
+
+const EXAMPLE: &str = include_str!("../inputs/01_test.txt");
+
+#[test]
+fn top_k() {
+    assert_eq!(day01::top_k(EXAMPLE.as_bytes(), 2).unwrap(), [24000, 11000]);
+    assert_eq!(day01::top_k(EXAMPLE.as_bytes(), 10).unwrap().len(), 5);
+    assert!(day01::top_k(EXAMPLE.as_bytes(), 0).unwrap().is_empty());
+    assert_eq!(
+        day01::top_k(EXAMPLE.as_bytes(), usize::MAX).unwrap().len(),
+        5
+    );
+}
+
+// Generates `elves` elves of three items each without building the input in memory
+struct Generated {
+    elves: u64,
+    next: u64,
+    pending: Vec<u8>,
+}
+
+impl Read for Generated {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if self.pending.is_empty() && self.next < self.elves {
+            let n = self.next;
+            self.pending = format!("{}\n{}\n{}\n\n", n % 997, n % 1009, n % 1013).into_bytes();
+            self.next += 1;
+        }
+        let len = buf.len().min(self.pending.len());
+        buf[..len].copy_from_slice(&self.pending[..len]);
+        self.pending.drain(..len);
+        Ok(len)
+    }
+}
+
+#[test]
+fn top_k_streaming() {
+    let reader = BufReader::new(Generated {
+        elves: 200_000,
+        next: 0,
+        pending: Vec::new(),
+    });
+    let top = day01::top_k(reader, 3).unwrap();
+
+    let mut totals = (0..200_000u64)
+        .map(|n| n % 997 + n % 1009 + n % 1013)
+        .collect::<Vec<_>>();
+    totals.sort_unstable_by(|a, b| b.cmp(a));
+    assert_eq!(top, totals[..3]);
+}
+
+#[test]
+fn invalid_calories() {
+    let err = day01::top_k("100\n\n200\n2x0\n".as_bytes(), 1).unwrap_err();
+    let err = err.downcast::<ParseError>().unwrap();
+    assert_eq!((err.day, err.line, err.column), (1, 4, 1));
+    assert_eq!(err.text, "2x0");
+}
+
+#[test]
+fn calories_overflow() {
+    let input = format!("1\n\n{}\n1\n", u64::MAX);
+    let err = day01::top_k(input.as_bytes(), 1).unwrap_err();
+    let err = err.downcast::<ParseError>().unwrap();
+    assert_eq!((err.day, err.line, err.column), (1, 4, 1));
+    assert_eq!(err.text, "1");
+}
+
+#[test]
+fn huge_totals() {
+    let max = u64::MAX;
+    let report = day01::report(&format!("{max}\n\n{}\n", max - 2)).unwrap();
+    assert_eq!(report.median(), Some(max as f64 - 1.0));
+    assert_eq!(report.mean(), Some(max as f64));
+
+    // Buckets over the whole u64 range
+    let report = day01::report(&format!("0\n\n{max}\n")).unwrap();
+    let histogram = report.histogram(2, 1);
+    assert_eq!(histogram.lines().count(), 2);
+    assert!(histogram.contains(&format!("-{max}")));
+}
+
+#[test]
+fn report() {
+    let report = day01::report(EXAMPLE).unwrap();
+    assert_eq!(
+        report.elves[2],
+        day01::Elf {
+            index: 3,
+            items: 2,
+            total: 11000,
+            min: 5000,
+            max: 6000,
+        }
+    );
+    assert_eq!(report.mean(), Some(11000.0));
+    assert_eq!(report.median(), Some(10000.0));
+    assert_eq!(report.percentile(25), Some(6000));
+    assert_eq!(report.percentile(100), Some(24000));
+    assert_eq!(report.percentile(0), Some(4000));
+
+    let histogram = report.histogram(2, 4);
+    assert_eq!(
+        histogram,
+        "   4000-14000       4 ####\n  14001-24000       1 #\n"
+    );
+    // No buckets past the largest total
+    assert_eq!(
+        day01::report("5\n\n7\n").unwrap().histogram(10, 1),
+        "      5-5           1 #\n      6-6           0\n      7-7           1 #\n"
+    );
+    assert!(report
+        .csv()
+        .starts_with("elf,items,total,min,max\n1,3,6000,1000,3000\n"));
+    assert!(report.table().contains("median: 10000.0"));
+    assert!(report
+        .csv()
+        .ends_with("\nstat,value\nelves,5\nmean,11000.0\nmedian,10000.0\np10,4000\np25,6000\np75,11000\np90,24000\np99,24000\n"));
+}
@@ -0,0 +1,148 @@
+use aoc2022::days::day02::{
+    self,
+    analysis::{analyze, best_and_worst},
+    game::Game,
+    tournament::{entrants, play, round_robin, Fixed, Frequency, Random, Rng, Strategy},
+    MatchResult, RPS,
+};
+
+const EXAMPLE: &str = include_str!("../inputs/02_test.txt");
+
+#[test]
+fn standard_game() {
+    for game in [
+        Game::standard(),
+        Game::parse(include_str!("../rules/rps.txt")).unwrap(),
+    ] {
+        let rounds = game.parse_rounds(EXAMPLE).unwrap();
+        assert_eq!(game.total(&rounds), 15);
+        assert_eq!(game.fixed_total(&rounds), Some(12));
+    }
+}
+
+#[test]
+fn report() {
+    let report = day02::report(EXAMPLE, "standard", &Game::standard()).unwrap();
+    assert_eq!(
+        report.table(),
+        "Rules: standard (Rock, Paper, Scissors)\nPart 1: 15\nPart 2: 12\n"
+    );
+    assert_eq!(report.csv(), "rules,part1,part2\nstandard,15,12\n");
+
+    // Y asks for a win, and nothing beats Paper when it is the only weapon
+    let game = Game::parse("weapon Paper 2 B Y\nwin 6 Y\n").unwrap();
+    let report = day02::report("B Y\n", "paper.txt", &game).unwrap();
+    assert_eq!(report.fixed_total, None);
+    assert!(report.table().ends_with("Part 2: n/a\n"));
+    assert_eq!(report.csv(), "rules,part1,part2\npaper.txt,5,\n");
+}
+
+#[test]
+fn rpsls() {
+    let game = Game::parse(include_str!("../rules/rpsls.txt")).unwrap();
+    let [spock, lizard, paper] = ["Spock", "Lizard", "Paper"].map(|w| game.weapon(w).unwrap());
+    assert_eq!(game.play(spock, lizard), MatchResult::Loss);
+    assert_eq!(game.play(paper, spock), MatchResult::Win);
+    assert_eq!(
+        game.required_for(MatchResult::Win, lizard),
+        game.weapon("Rock")
+    );
+
+    // Y means Lizard in part 1 and a draw in part 2
+    let rounds = game.parse_rounds("A Y\n").unwrap();
+    assert_eq!(game.total(&rounds), 4);
+    assert_eq!(game.fixed_total(&rounds), Some(1 + 3));
+    assert!(game.parse_rounds("F Y\n").is_err());
+}
+
+#[test]
+fn cyclic_games_are_balanced() {
+    let game = Game::cyclic(&["a", "b", "c", "d", "e", "f", "g"]).unwrap();
+    for us in 0..7 {
+        let wins = (0..7)
+            .filter(|them| game.play(us, *them) == MatchResult::Win)
+            .count();
+        assert_eq!(wins, 3);
+    }
+    assert!(Game::cyclic(&["a", "b"]).is_err());
+}
+
+#[test]
+fn invalid_rules() {
+    for rules in [
+        "",
+        "weapon Rock 1 A X\nweapon Rock 2 B Y\n",
+        "weapon Rock 1 A X\nbeats Rock Paper\n",
+        "weapon Rock 1 A X\nweapon Paper 2 B Y\nbeats Rock Paper\nbeats Paper Rock\n",
+        "weapon Rock 1 A X\nweapon Paper 2 B Y\ncyclic\n",
+        "weapon Rock one A X\n",
+        "shoot\n",
+    ] {
+        assert!(Game::parse(rules).is_err(), "{rules:?}");
+    }
+}
+
+#[test]
+fn analysis() {
+    let matches = day02::parse(EXAMPLE).unwrap();
+    let analysis = analyze(&matches);
+    assert_eq!(analysis.weapons.len(), 6);
+    assert_eq!(analysis.outcomes.len(), 6);
+
+    // The puzzle's own readings come first
+    assert_eq!(analysis.weapons[0].total, 15);
+    assert_eq!(analysis.outcomes[0].total, 12);
+
+    let (best, worst) = best_and_worst(&analysis.weapons).unwrap();
+    assert!(analysis
+        .weapons
+        .iter()
+        .all(|i| (worst.total..=best.total).contains(&i.total)));
+    // Winning every round
+    assert_eq!(best.mapping, [RPS::Scissors, RPS::Paper, RPS::Rock]);
+    assert_eq!(best.total, 24);
+    assert!(analysis.table().contains("best"));
+    assert_eq!(analysis.csv().lines().count(), 13);
+}
+
+#[test]
+fn tournament() {
+    let mut a = Rng::new(42);
+    let mut b = Rng::new(42);
+    assert!((0..100).all(|_| a.next_u64() == b.next_u64()));
+
+    // Rock against Paper loses every round
+    let (rock, paper) = play(&mut Fixed(RPS::Rock), &mut Fixed(RPS::Paper), 10);
+    assert_eq!((rock, paper), (10, 80));
+
+    // Frequency counting opens with Paper, then plays what beats the opponent
+    let (freq, rock) = play(&mut Frequency::default(), &mut Fixed(RPS::Rock), 10);
+    assert_eq!((freq, rock), (80, 10));
+    let (freq, paper) = play(&mut Frequency::default(), &mut Fixed(RPS::Paper), 10);
+    assert_eq!((freq, paper), (5 + 9 * 9, 5 + 9 * 2));
+
+    let mut random = Random::new(7);
+    let first = (0..20).map(|_| random.choose()).collect::<Vec<_>>();
+    random.reset();
+    assert_eq!((0..20).map(|_| random.choose()).collect::<Vec<_>>(), first);
+
+    let matches = day02::parse(EXAMPLE).unwrap();
+    let mut entrants = entrants(&matches, 1);
+    let n = entrants.len();
+    let leaderboard = round_robin(&mut entrants, 100);
+    assert_eq!(leaderboard.standings.len(), n);
+    for s in leaderboard.standings.iter() {
+        assert_eq!(s.wins + s.draws + s.losses, n - 1);
+    }
+    let wins = leaderboard.standings.iter().map(|s| s.wins).sum::<usize>();
+    let losses = leaderboard
+        .standings
+        .iter()
+        .map(|s| s.losses)
+        .sum::<usize>();
+    assert_eq!(wins, losses);
+    assert!(leaderboard
+        .standings
+        .windows(2)
+        .all(|w| w[0].points() >= w[1].points()));
+}
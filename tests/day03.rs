@@ -0,0 +1,78 @@
+use aoc2022::{
+    days::day03::{badges, parse, part1, report, validate, ItemSet, Problem},
+    ParseError,
+};
+
+#[test]
+fn item_set() {
+    let a = "vJrwpWtwJgWr".chars().collect::<ItemSet>();
+    let b = "hcsFMMfFFhFp".chars().collect::<ItemSet>();
+
+    assert_eq!((a & b).iter().collect::<String>(), "p");
+    assert_eq!(a.len(), 8);
+    assert!(a.contains('J') && !a.contains('j'));
+    assert_eq!((a | b).len(), 14);
+    assert!((a & ItemSet::EMPTY).is_empty());
+    assert_eq!(ItemSet::ALL.len(), 52);
+    assert_eq!(
+        ItemSet::ALL.iter().collect::<String>(),
+        ('a'..='z').chain('A'..='Z').collect::<String>()
+    );
+}
+
+#[test]
+fn shared_items() {
+    let rucksacks = parse(include_str!("../inputs/03_test.txt")).unwrap();
+    let shared = rucksacks
+        .iter()
+        .flat_map(|r| r.shared().iter())
+        .collect::<String>();
+    assert_eq!(shared, "pLPvts");
+}
+
+#[test]
+fn groups_and_validation() {
+    let rucksacks = parse(include_str!("../inputs/03_test.txt")).unwrap();
+    assert_eq!(badges(&rucksacks, 3).unwrap(), 70);
+    assert!(badges(&rucksacks, 0).is_err());
+    assert!(badges(&rucksacks, 4).is_err());
+    assert!(validate(&rucksacks, 3).is_empty());
+
+    let rucksacks = parse("abcabc\nabcd\nabcdef\nxyzxqq\n").unwrap();
+    assert!(part1(&rucksacks).is_err());
+    assert_eq!(
+        validate(&rucksacks, 3),
+        [
+            Problem::Shared(1, "abc".chars().collect()),
+            Problem::Badge(1, "abc".chars().collect()),
+            Problem::Shared(2, ItemSet::EMPTY),
+            Problem::Shared(3, ItemSet::EMPTY),
+            Problem::IncompleteGroup(4, 1),
+        ]
+    );
+    assert_eq!(
+        validate(&rucksacks, 3)[1].to_string(),
+        "group at line 1: ambiguous badge: abc"
+    );
+
+    let err = parse("abcabc\nabc\n")
+        .err()
+        .unwrap()
+        .downcast::<ParseError>()
+        .unwrap();
+    assert_eq!((err.line, err.column), (2, 1));
+    assert_eq!(err.message, "odd number of items");
+}
+
+#[test]
+fn reports() {
+    let report = report("abcabc\nxyzxqq\n", 2).unwrap();
+    assert_eq!(report.rucksacks, 2);
+    assert_eq!(
+        report.table(),
+        "2 rucksacks, groups of 2: 2 problem(s)\n\
+         line 1: several items in both compartments: abc\n\
+         group at line 1: no badge\n"
+    );
+    assert!(report.csv().starts_with("line,problem\n1,several items"));
+}
@@ -0,0 +1,89 @@
+use aoc2022::{days::day04::*, interval::Interval};
+
+const EXAMPLE: &str = include_str!("../inputs/04_test.txt");
+
+fn brute_force_pairs(tasks: &Assignments) -> Vec<(Elf, Elf)> {
+    let elves = elves(tasks);
+    let mut pairs = Vec::new();
+    for (i, (a, x)) in elves.iter().enumerate() {
+        for (b, y) in elves[i + 1..].iter() {
+            if x.overlaps(y) {
+                pairs.push((*a.min(b), *a.max(b)));
+            }
+        }
+    }
+    pairs.sort();
+    pairs
+}
+
+#[test]
+fn overlapping_pairs_across_lines() {
+    let tasks = parse(EXAMPLE).unwrap();
+    let pairs = overlapping_pairs(&tasks);
+    assert_eq!(pairs, brute_force_pairs(&tasks));
+    assert_eq!(count_overlapping_pairs(&tasks), pairs.len());
+    assert!(pairs.contains(&(
+        Elf {
+            line: 1,
+            position: 2
+        },
+        Elf {
+            line: 3,
+            position: 1
+        }
+    )));
+}
+
+#[test]
+fn contested_sections() {
+    let tasks = parse(EXAMPLE).unwrap();
+    assert_eq!(
+        most_contested(&tasks),
+        Some(Contested {
+            sections: Interval::point(6),
+            elves: 8
+        })
+    );
+    assert_eq!(max_coverage(&tasks), 8);
+    assert_eq!(max_coverage(&parse("1-2,3-4\n").unwrap()), 1);
+    assert_eq!(max_coverage(&Vec::new()), 0);
+
+    let tasks = parse("1-9223372036854775807\n5-9223372036854775807,3-4\n").unwrap();
+    assert_eq!(
+        most_contested(&tasks),
+        Some(Contested {
+            sections: Interval::new(3, i64::MAX).unwrap(),
+            elves: 2
+        })
+    );
+}
+
+#[test]
+fn any_number_of_ranges() {
+    let tasks = parse("1-3\n1-5,2-4,8-9\n1-2,3-4,4-6\n").unwrap();
+    assert_eq!(tasks.iter().map(Vec::len).collect::<Vec<_>>(), [1, 3, 3]);
+    assert_eq!(part1(&tasks), 1);
+    assert_eq!(part2(&tasks), 2);
+    assert_eq!(
+        most_contested(&tasks),
+        Some(Contested {
+            sections: Interval::new(2, 4).unwrap(),
+            elves: 4
+        })
+    );
+    assert!(parse("1-3\n\n2-4\n").is_err());
+}
+
+#[test]
+fn reports() {
+    let report = report("2-4,6-8\n3-3,9-9\n").unwrap();
+    assert_eq!(report.overlapping_pairs, 1);
+    assert_eq!(report.uncovered.to_string(), "5-5");
+    assert!(report
+        .table()
+        .starts_with("Sections 2-9: 7 covered, 1 uncovered\n"));
+    assert_eq!(
+        report.csv(),
+        "start,end,covered\n2,4,true\n5,5,false\n6,9,true\n"
+    );
+}
@@ -0,0 +1,238 @@
+use aoc2022::{days::day05::*, ParseError};
+
+fn error(input: &str) -> ParseError {
+    match parse(input) {
+        Ok(_) => panic!("expected a parse error"),
+        Err(e) => e.downcast::<ParseError>().unwrap(),
+    }
+}
+
+#[test]
+fn diagram() {
+    let puzzle = parse(include_str!("../inputs/05_test.txt")).unwrap();
+    assert_eq!(
+        puzzle.stacks,
+        [vec!["Z", "N"], vec!["M", "C", "D"], vec!["P"]]
+    );
+    assert_eq!(puzzle.commands[0], (1, 2, 1));
+
+    // Wide labels, a wider number row and an empty stack
+    let puzzle = parse(
+        "\
+[AB]        [ZZ]
+[CD] [EF]   [YY]   [X1]
+ 1    2    3 4     5
+
+move 1 from 1 to 3
+",
+    )
+    .unwrap();
+    assert_eq!(puzzle.stacks.len(), 5);
+    assert_eq!(puzzle.stacks[0], ["CD", "AB"]);
+    assert_eq!(puzzle.stacks[1], ["EF"]);
+    assert!(puzzle.stacks[2].is_empty());
+    assert_eq!(puzzle.stacks[3], ["YY", "ZZ"]);
+    assert_eq!(puzzle.stacks[4], ["X1"]);
+}
+
+#[test]
+fn invalid_diagrams() {
+    let err = error("[A] [B]\n 1   3\n\nmove 1 from 1 to 2\n");
+    assert_eq!((err.line, err.column), (2, 6));
+    assert_eq!(err.message, "expected stack number 2");
+
+    let err = error("[A]     [B]\n 1   2\n\nmove 1 from 1 to 2\n");
+    assert_eq!((err.line, err.column), (1, 9));
+    assert_eq!(err.message, "crate is not above a stack");
+
+    let err = error("[A] B\n 1  2\n\nmove 1 from 1 to 2\n");
+    assert_eq!(err.message, "expected a crate like [X]");
+
+    let err = error("[A]\n 1\n");
+    assert_eq!(err.message, "missing rearrangement procedure");
+}
+
+fn moved(crane: &dyn Crane) -> Vec<String> {
+    let stacks = vec![
+        vec!["A", "B", "C", "D", "E"]
+            .into_iter()
+            .map(String::from)
+            .collect(),
+        Vec::new(),
+    ];
+    let puzzle = Puzzle {
+        stacks,
+        commands: vec![(5, 1, 2)],
+        first_line: 1,
+    };
+    let mut stacks = simulate(crane, &puzzle).unwrap();
+    assert!(stacks[0].is_empty());
+    stacks.pop().unwrap()
+}
+
+#[test]
+fn cranes() {
+    assert_eq!(moved(&CrateMover9000), ["E", "D", "C", "B", "A"]);
+    assert_eq!(moved(&CrateMover9001), ["A", "B", "C", "D", "E"]);
+    assert_eq!(moved(&Flipping), ["E", "D", "C", "B", "A"]);
+    assert_eq!(moved(&Limited { capacity: 2 }), ["D", "E", "B", "C", "A"]);
+    assert_eq!(moved(&Limited { capacity: 1 }), moved(&CrateMover9000));
+    assert_eq!(moved(&Limited { capacity: 5 }), moved(&CrateMover9001));
+
+    let puzzle = parse(include_str!("../inputs/05_test.txt")).unwrap();
+    assert_eq!(tops(&simulate(&CrateMover9000, &puzzle).unwrap()), "CMZ");
+    assert_eq!(
+        tops(&simulate(&Limited { capacity: 2 }, &puzzle).unwrap()),
+        "MCZ"
+    );
+    assert_eq!(
+        tops(&simulate(crane("limit:1").unwrap().as_ref(), &puzzle).unwrap()),
+        "CMZ"
+    );
+    assert!(crane("limit:0").is_none() && crane("9002").is_none());
+}
+
+#[test]
+fn rendering() {
+    let input = include_str!("../inputs/05_test.txt");
+    let puzzle = parse(input).unwrap();
+    let (diagram, _) = input.split_once("\n\n").unwrap();
+    assert_eq!(render(&puzzle.stacks), format!("{diagram}\n"));
+
+    let input = include_str!("../inputs/05.txt");
+    let (diagram, _) = input.split_once("\n\n").unwrap();
+    assert_eq!(
+        render(&parse(input).unwrap().stacks),
+        format!("{diagram}\n")
+    );
+
+    let wide = [vec!["AB".to_string()], Vec::new(), vec!["C".to_string()]];
+    assert_eq!(render(&wide), "[AB]      [C]\n 1    2    3  \n");
+    assert_eq!(
+        parse(&format!("{}\nmove 1 from 1 to 2\n", render(&wide)))
+            .unwrap()
+            .stacks,
+        wide
+    );
+
+    let states = replay(&CrateMover9000, &puzzle)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(states.len(), puzzle.commands.len() + 1);
+    assert_eq!(states[0], puzzle.stacks);
+    assert_eq!(tops(&states[4]), "CMZ");
+
+    assert_eq!(
+        diff(&states[0], &states[1]),
+        "\
+-     [D]
++ [D]
+  [N] [C]
+  [Z] [M] [P]
+   1   2   3
+"
+    );
+}
+
+#[test]
+fn invalid_commands() {
+    let procedure = |commands: &str| {
+        let input = format!("[A]\n[B] [C]\n 1   2\n\n{commands}");
+        let puzzle = parse(&input).unwrap();
+        simulate(&CrateMover9000, &puzzle).unwrap_err()
+    };
+
+    let puzzle = parse(
+        "[A]\n[B] [C]\n 1   2\n\nmove 1 from 1 to 2\nmove 2 from 1 to 2\nmove 1 from 2 to 1\n",
+    )
+    .unwrap();
+    let states = replay(&CrateMover9000, &puzzle).collect::<Vec<_>>();
+    assert_eq!(states.len(), 3);
+    assert!(states[1].is_ok());
+    assert_eq!(states[2].as_ref().unwrap_err().step, 2);
+
+    let err = procedure("move 1 from 1 to 2\nmove 2 from 1 to 2\n");
+    assert_eq!((err.step, err.line, err.command), (2, 6, (2, 1, 2)));
+    assert_eq!(err.reason, "stack 1 only has 1 crate(s)");
+    assert_eq!(
+        err.to_string(),
+        "day 5, line 6: cannot move 2 from 1 to 2: stack 1 only has 1 crate(s)"
+    );
+
+    assert_eq!(
+        procedure("move 1 from 0 to 2\n").reason,
+        "there is no stack 0"
+    );
+    assert_eq!(
+        procedure("move 1 from 1 to 3\n").reason,
+        "there is no stack 3"
+    );
+    assert_eq!(parse("[A]\n 1\n\nmove 1 from 1 to 2\n").unwrap().line(1), 4);
+}
+
+#[test]
+fn undo() {
+    let puzzle = parse(include_str!("../inputs/05.txt")).unwrap();
+    let cranes: [Box<dyn Crane>; 5] = [
+        Box::new(CrateMover9000),
+        Box::new(CrateMover9001),
+        Box::new(Flipping),
+        Box::new(Limited { capacity: 2 }),
+        Box::new(Limited { capacity: 5 }),
+    ];
+    for crane in cranes.iter() {
+        let states = replay(crane.as_ref(), &puzzle)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        let mut executor = Executor::new(crane.as_ref(), &puzzle);
+        executor.run().unwrap();
+        assert_eq!(executor.steps(), puzzle.commands.len());
+
+        let inverse = Puzzle {
+            stacks: executor.stacks().to_vec(),
+            commands: executor.inverse(),
+            first_line: 1,
+        };
+        assert_eq!(simulate(crane.as_ref(), &inverse).unwrap(), puzzle.stacks);
+
+        executor.rewind(100);
+        assert_eq!(executor.stacks(), states[100]);
+        executor.rewind(0);
+        assert_eq!(executor.stacks(), puzzle.stacks);
+        assert!(!executor.back());
+    }
+}
+
+#[test]
+fn reports() {
+    let input = include_str!("../inputs/05_test.txt");
+    assert!(report(input, &["9002".to_string()]).is_err());
+
+    let report = report(input, &["9000".to_string(), "limit:2".to_string()]).unwrap();
+    assert_eq!(
+        report.table(),
+        "3 stacks, 4 moves\nCrateMover 9000: CMZ\nlimited to 2 crates: MCZ\n"
+    );
+    assert_eq!(report.csv(), "crane,tops\n9000,CMZ\nlimit:2,MCZ\n");
+}
+
+#[test]
+fn views() {
+    let input = include_str!("../inputs/05_test.txt");
+    assert_eq!(
+        watch(input, &CrateMover9000, View::Step(1)).unwrap(),
+        "Crane: CrateMover 9000\nStep 1: move 1 from 2 to 1\n[D]\n[N] [C]\n[Z] [M] [P]\n 1   2   3 \n"
+    );
+    assert_eq!(
+        watch(input, &CrateMover9001, View::Inverse).unwrap(),
+        "Crane: CrateMover 9001\nmove 1 from 2 to 1\nmove 2 from 1 to 2\nmove 3 from 3 to 1\nmove 1 from 1 to 2\n"
+    );
+    assert!(watch(input, &CrateMover9000, View::Step(5)).is_err());
+    assert_eq!(
+        watch(input, &CrateMover9000, View::Steps)
+            .unwrap()
+            .matches(" 1   2   3 \n")
+            .count(),
+        5
+    );
+}
@@ -0,0 +1,116 @@
+use std::io::{BufReader, Read};
+
+use aoc2022::days::day06::*;
+
+// Hands out at most a few bytes per read, so markers span buffer boundaries
+struct Trickle<'a>(&'a [u8]);
+
+impl Read for Trickle<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let n = buf.len().min(3).min(self.0.len());
+        buf[..n].copy_from_slice(&self.0[..n]);
+        self.0 = &self.0[n..];
+        Ok(n)
+    }
+}
+
+fn brute_force(data: &[u8], size: usize) -> Vec<usize> {
+    (size.max(1)..=data.len())
+        .filter(|end| {
+            let window = &data[end - size..*end];
+            (0..window.len()).all(|i| !window[i + 1..].contains(&window[i]))
+        })
+        .collect()
+}
+
+#[test]
+fn first_markers() {
+    let examples = [
+        ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5, 23),
+        ("nppdvjthqldpwncqszvftbrmjlhg", 6, 23),
+        ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10, 29),
+        ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11, 26),
+    ];
+    for (data, packet, message) in examples {
+        assert_eq!(first_marker(data.as_bytes(), PACKET), Some(packet));
+        assert_eq!(first_marker(data.as_bytes(), MESSAGE), Some(message));
+    }
+
+    assert_eq!(first_marker(b"aaaa", 1), Some(1));
+    assert_eq!(first_marker(b"abcabc", 4), None);
+    assert_eq!(first_marker(b"", 4), None);
+    assert!(part1(b"aaaaaaa").is_err());
+}
+
+#[test]
+fn every_marker() {
+    let data = include_bytes!("../inputs/06.txt");
+    for size in [1, 2, 4, 7, 14, 20] {
+        let expected = brute_force(data, size);
+        assert_eq!(all_markers(data, size), expected);
+
+        let streamed = markers(BufReader::with_capacity(5, Trickle(data)), size)
+            .collect::<anyhow::Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(streamed, expected);
+    }
+    assert_eq!(all_markers(b"abcabca", 3), [3, 4, 5, 6, 7]);
+}
+
+#[test]
+fn streams() {
+    let data = include_bytes!("../inputs/06.txt");
+    let reader = BufReader::with_capacity(7, Trickle(data));
+    assert_eq!(first_marker_in(reader, MESSAGE).unwrap(), Some(3444));
+    assert_eq!(first_marker_in(&b"abab"[..], 3).unwrap(), None);
+}
+
+#[test]
+fn framing() {
+    let data = include_bytes!("../inputs/06_test.txt").trim_ascii_end();
+    let packets = frames(data, PACKET);
+    assert_eq!(packets.len(), 7);
+    assert_eq!(
+        packets[0],
+        Frame {
+            marker: 3..7,
+            payload: 7..7
+        }
+    );
+    assert_eq!(packets[6].payload, 31..31);
+
+    let messages = frames(data, MESSAGE);
+    assert_eq!(
+        messages,
+        [Frame {
+            marker: 15..29,
+            payload: 29..31
+        }]
+    );
+
+    // Frames tile the stream from the first marker on, and every marker is the
+    // first one found after the previous frame
+    let data = include_bytes!("../inputs/06.txt").trim_ascii_end();
+    for size in [PACKET, MESSAGE] {
+        let frames = frames(data, size);
+        assert_eq!(frames[0].marker.end, first_marker(data, size).unwrap());
+        for pair in frames.windows(2) {
+            assert_eq!(pair[0].payload.end, pair[1].marker.start);
+            let after = pair[0].marker.end;
+            assert_eq!(
+                first_marker(&data[after..], size),
+                Some(pair[1].marker.end - after)
+            );
+        }
+        assert_eq!(frames.last().unwrap().payload.end, data.len());
+    }
+
+    let scan = scan(b"abcdaaabcd");
+    assert_eq!(scan.packets.len(), 2);
+    assert!(scan.messages.is_empty());
+    assert!(scan.table().contains("    2     10 abcd"));
+    assert_eq!(
+        scan.csv(),
+        "kind,index,at,marker,payload\npacket,1,4,abcd,aa\npacket,2,10,abcd,\n"
+    );
+}
@@ -0,0 +1,102 @@
+use aoc2022::{days::*, ParseError};
+
+fn error<T>(result: anyhow::Result<T>) -> ParseError {
+    match result {
+        Ok(_) => panic!("expected a parse error"),
+        Err(e) => e.downcast::<ParseError>().unwrap(),
+    }
+}
+
+#[test]
+fn day02_bad_letter() {
+    let err = error(day02::parse("A Y\nB Q\n"));
+    assert_eq!((err.day, err.line, err.column), (2, 2, 3));
+    assert_eq!(err.text, "Q");
+}
+
+#[test]
+fn day02_missing_letter() {
+    let err = error(day02::parse("A Y\nB\n"));
+    assert_eq!((err.day, err.line, err.column), (2, 2, 1));
+    assert_eq!(err.text, "B");
+}
+
+#[test]
+fn day04_bad_section() {
+    let err = error(day04::parse("2-4,6-8\n2-3,4-x\n"));
+    assert_eq!((err.day, err.line, err.column), (4, 2, 7));
+    assert_eq!(err.text, "x");
+}
+
+#[test]
+fn day08_bad_digit() {
+    let err = error(day08::parse("123\n4x6\n789\n"));
+    assert_eq!((err.day, err.line, err.column), (8, 2, 2));
+    assert_eq!(err.text, "x6");
+}
+
+#[test]
+fn day09_bad_direction() {
+    let err = error(day09::parse("R 4\nX 2\n"));
+    assert_eq!((err.day, err.line, err.column), (9, 2, 1));
+}
+
+#[test]
+fn day10_bad_value() {
+    let err = error(day10::parse("noop\naddx x\nnoop\n"));
+    assert_eq!((err.day, err.line, err.column), (10, 2, 1));
+    assert_eq!(err.text, "addx x");
+}
+
+#[test]
+fn day13_unclosed_list() {
+    let err = error(day13::parse("[1,2]\n[3\n"));
+    assert_eq!((err.day, err.line), (13, 1));
+}
+
+#[test]
+fn day07_disk_sizes() {
+    let roomy = day07::parse("$ cd /\n$ ls\n100 a\n").unwrap();
+    assert_eq!(day07::part2(&roomy).unwrap(), 0);
+
+    let full = day07::parse("$ cd /\n$ ls\n70000001 a\n").unwrap();
+    assert!(day07::part2(&full).is_err());
+}
+
+fn monkey(id: usize, test: u64, if_true: usize, if_false: usize) -> String {
+    format!(
+        "Monkey {id}:\n  Starting items: 79, 98\n  Operation: new = old * 19\n  \
+         Test: divisible by {test}\n    If true: throw to monkey {if_true}\n    \
+         If false: throw to monkey {if_false}\n"
+    )
+}
+
+#[test]
+fn day11_inconsistent_monkeys() {
+    assert!(day11::parse(&format!("{}\n{}", monkey(0, 2, 1, 1), monkey(1, 3, 0, 0))).is_ok());
+
+    let err = error(day11::parse(&monkey(0, 2, 3, 0)));
+    assert_eq!((err.day, err.line, err.column), (11, 5, 30));
+    assert_eq!(err.message, "there is no monkey 3");
+
+    let err = error(day11::parse(&monkey(0, 0, 0, 0)));
+    assert_eq!((err.line, err.column), (4, 22));
+    assert_eq!(err.message, "cannot divide by 0");
+
+    let err = error(day11::parse(&format!(
+        "{}\n{}",
+        monkey(1, 2, 0, 1),
+        monkey(0, 3, 0, 1)
+    )));
+    assert_eq!((err.line, err.column), (1, 8));
+    assert_eq!(err.message, "expected monkey 0");
+}
+
+#[test]
+fn day14_deep_cave() {
+    // The floor is deep enough for sand to pile up left of x = 0
+    let paths = day14::parse("0,600 -> 2,600\n").unwrap();
+    assert_eq!(day14::part1(&paths), 0);
+    // The full triangle less the three rocks and the cell sheltered under them
+    assert_eq!(day14::part2(&paths), 602 * 602 - 4);
+}
@@ -0,0 +1,107 @@
+use aoc2022::days::*;
+
+const SCREEN: &str = "\
+##..##..##..##..##..##..##..##..##..##..\n\
+###...###...###...###...###...###...###.\n\
+####....####....####....####....####....\n\
+#####.....#####.....#####.....#####.....\n\
+######......######......######......####\n\
+#######.......#######.......#######.....";
+
+#[test]
+fn day01() {
+    let input = day01::parse(include_str!("../inputs/01_test.txt")).unwrap();
+    assert_eq!(day01::part1(&input), 24000);
+    assert_eq!(day01::part2(&input), 45000);
+}
+
+#[test]
+fn day02() {
+    let input = day02::parse(include_str!("../inputs/02_test.txt")).unwrap();
+    assert_eq!(day02::part1(&input), 15);
+    assert_eq!(day02::part2(&input), 12);
+}
+
+#[test]
+fn day03() {
+    let input = day03::parse(include_str!("../inputs/03_test.txt")).unwrap();
+    assert_eq!(day03::part1(&input).unwrap(), 157);
+    assert_eq!(day03::part2(&input).unwrap(), 70);
+}
+
+#[test]
+fn day04() {
+    let input = day04::parse(include_str!("../inputs/04_test.txt")).unwrap();
+    assert_eq!(day04::part1(&input), 2);
+    assert_eq!(day04::part2(&input), 4);
+}
+
+#[test]
+fn day05() {
+    let input = day05::parse(include_str!("../inputs/05_test.txt")).unwrap();
+    assert_eq!(day05::part1(&input).unwrap(), "CMZ");
+    assert_eq!(day05::part2(&input).unwrap(), "MCD");
+}
+
+#[test]
+fn day06() {
+    let input = day06::parse(include_str!("../inputs/06_test.txt")).unwrap();
+    assert_eq!(day06::part1(&input).unwrap(), 7);
+    assert_eq!(day06::part2(&input).unwrap(), 29);
+}
+
+#[test]
+fn day07() {
+    let input = day07::parse(include_str!("../inputs/07_test.txt")).unwrap();
+    assert_eq!(day07::part1(&input), 95437);
+    assert_eq!(day07::part2(&input).unwrap(), 24933642);
+}
+
+#[test]
+fn day08() {
+    let input = day08::parse(include_str!("../inputs/08_test.txt")).unwrap();
+    assert_eq!(day08::part1(&input), 21);
+    assert_eq!(day08::part2(&input), 8);
+}
+
+#[test]
+fn day09() {
+    let input = day09::parse(include_str!("../inputs/09_test.txt")).unwrap();
+    assert_eq!(day09::part1(&input), 13);
+    assert_eq!(day09::part2(&input), 1);
+}
+
+#[test]
+fn day10() {
+    let input = day10::parse(include_str!("../inputs/10_test.txt")).unwrap();
+    assert_eq!(day10::part1(&input), 13140);
+    assert_eq!(day10::part2(&input), SCREEN);
+}
+
+#[test]
+fn day11() {
+    let input = day11::parse(include_str!("../inputs/11_test.txt")).unwrap();
+    assert_eq!(day11::part1(&input), 10605);
+    assert_eq!(day11::part2(&input), 2713310158);
+}
+
+#[test]
+fn day12() {
+    let input = day12::parse(include_str!("../inputs/12_test.txt")).unwrap();
+    assert_eq!(day12::part1(&input).unwrap(), 31);
+    assert_eq!(day12::part2(&input).unwrap(), 29);
+}
+
+#[test]
+fn day13() {
+    let input = day13::parse(include_str!("../inputs/13_test.txt")).unwrap();
+    assert_eq!(day13::part1(&input), 13);
+    assert_eq!(day13::part2(&input), 140);
+}
+
+#[test]
+fn day14() {
+    let input = day14::parse(include_str!("../inputs/14_test.txt")).unwrap();
+    assert_eq!(day14::part1(&input), 24);
+    assert_eq!(day14::part2(&input), 93);
+}
@@ -0,0 +1,61 @@
+use aoc2022::{grid::GridError, Grid};
+
+fn grid(input: &str) -> Grid<char> {
+    Grid::parse(input, Some).unwrap()
+}
+
+#[test]
+fn parse_and_display() {
+    let g = grid("abc\ndef\n");
+    assert_eq!((g.width(), g.height()), (3, 2));
+    assert_eq!(g[(1, 2)], 'f');
+    assert_eq!(g.get((2, 0)), None);
+    assert_eq!(g.to_string(), "abc\ndef");
+
+    assert_eq!(
+        Grid::parse("12\n3\n", |c| c.to_digit(10)),
+        Err(GridError::Ragged { row: 1 })
+    );
+    assert_eq!(
+        Grid::parse("12\n3x\n", |c| c.to_digit(10)),
+        Err(GridError::InvalidCell { row: 1, col: 1 })
+    );
+}
+
+#[test]
+fn neighbors() {
+    let g = grid("abc\ndef\nghi\n");
+    let corner = g.neighbors4((0, 0)).map(|p| g[p]).collect::<String>();
+    assert_eq!(corner, "db");
+    let center = g.neighbors8((1, 1)).map(|p| g[p]).collect::<String>();
+    assert_eq!(center, "abcdfghi");
+    assert_eq!(g.neighbors8((2, 2)).count(), 3);
+}
+
+#[test]
+fn rows_and_columns() {
+    let g = grid("abc\ndef\n");
+    assert_eq!(g.row(1), ['d', 'e', 'f']);
+    assert_eq!(g.rows().count(), 2);
+    let columns = g
+        .columns()
+        .map(|c| c.collect::<String>())
+        .collect::<Vec<_>>();
+    assert_eq!(columns, ["ad", "be", "cf"]);
+    assert_eq!(g.column(2).rev().collect::<String>(), "fc");
+}
+
+#[test]
+#[should_panic(expected = "column 3 is outside of a 3x2 grid")]
+fn column_out_of_range() {
+    grid("abc\ndef\n").column(3).count();
+}
+
+#[test]
+fn transform() {
+    let g = grid("abc\ndef\n");
+    assert_eq!(g.transpose().to_string(), "ad\nbe\ncf");
+    assert_eq!(g.rotate_cw().to_string(), "da\neb\nfc");
+    assert_eq!(g.rotate_ccw().to_string(), "cf\nbe\nad");
+    assert_eq!(g.rotate_cw().rotate_ccw(), g);
+}
@@ -0,0 +1,48 @@
+use aoc2022::interval::{Interval, IntervalSet};
+
+fn i(start: i64, end: i64) -> Interval {
+    Interval::new(start, end).unwrap()
+}
+
+#[test]
+fn intervals() {
+    assert_eq!(Interval::new(5, 4), None);
+    assert_eq!(i(2, 8).len(), 7);
+    assert!(i(2, 8).contains(&i(3, 7)));
+    assert!(!i(3, 7).contains(&i(2, 8)));
+    assert!(i(5, 7).overlaps(&i(7, 9)));
+    assert!(!i(2, 3).overlaps(&i(4, 5)));
+
+    assert_eq!(i(5, 7).intersection(&i(7, 9)), Some(Interval::point(7)));
+    assert_eq!(i(2, 3).intersection(&i(4, 5)), None);
+
+    assert_eq!(i(2, 3).union(&i(4, 5)), Some(i(2, 5)));
+    assert_eq!(i(6, 8).union(&i(2, 4)), None);
+
+    assert_eq!(i(6, 8).gap(&i(2, 4)), Some(Interval::point(5)));
+    assert_eq!(i(2, 3).gap(&i(4, 5)), None);
+    assert_eq!(i(2, 6).gap(&i(4, 8)), None);
+    assert_eq!(i(-5, -3).to_string(), "-5--3");
+}
+
+#[test]
+fn sets() {
+    let set = [i(10, 12), i(1, 3), i(4, 5), i(20, 20), i(11, 15)]
+        .into_iter()
+        .collect::<IntervalSet>();
+    assert_eq!(set.intervals(), [i(1, 5), i(10, 15), i(20, 20)]);
+    assert_eq!(set.len(), 12);
+    assert_eq!(set.span(), Some(i(1, 20)));
+    assert_eq!(set.gaps(), [i(6, 9), i(16, 19)]);
+    assert!(set.contains_point(13) && !set.contains_point(16));
+
+    assert_eq!(
+        set.uncovered(i(0, 25)).to_string(),
+        "0-0, 6-9, 16-19, 21-25"
+    );
+    assert_eq!(set.uncovered(i(11, 14)), IntervalSet::new());
+
+    let mut set = set;
+    set.insert(i(0, 30));
+    assert_eq!(set.intervals(), [i(0, 30)]);
+}
@@ -0,0 +1,23 @@
+use aoc2022::{days, json, Answer};
+
+#[test]
+fn answers() {
+    assert_eq!(json::answer(&Answer::Number(-12)), "-12");
+    assert_eq!(json::answer(&Answer::Text("CMZ".into())), r#""CMZ""#);
+    assert_eq!(
+        json::answer(&Answer::Text("#.\n\"x\"".into())),
+        r##""#.\n\"x\"""##
+    );
+}
+
+#[test]
+fn run() {
+    let day = days::get(10).unwrap();
+    let run = (day.run)(include_str!("../inputs/10_test.txt")).unwrap();
+    let out = json::run(day.number, &run);
+
+    assert!(out.starts_with(r#"{"day":10,"part1":13140,"part2":"#));
+    assert!(out.contains("..##..\\n###..."));
+    assert!(out.contains(r#","timings":{"parse_ns":"#));
+    assert!(out.ends_with("}}"));
+}
@@ -0,0 +1,97 @@
+use aoc2022::{
+    search::{astar, bfs, dijkstra},
+    Grid,
+};
+
+// '#' are walls, everything else can be walked on
+const MAZE: &str = "\
+S..#....
+.#.#.##.
+.#...#E.
+.####...
+";
+
+fn open(maze: &Grid<char>, pos: (usize, usize)) -> Vec<(usize, usize)> {
+    maze.neighbors4(pos).filter(|p| maze[*p] != '#').collect()
+}
+
+#[test]
+fn bfs_path() {
+    let maze = Grid::parse(MAZE, Some).unwrap();
+    let start = maze.position(|c| *c == 'S').unwrap();
+    let end = maze.position(|c| *c == 'E').unwrap();
+
+    let found = bfs([start], |p| open(&maze, *p), |p| *p == end).unwrap();
+    assert_eq!(found.cost, 14);
+    assert_eq!(found.path.len(), 15);
+    assert_eq!(found.path.first(), Some(&start));
+    assert_eq!(found.path.last(), Some(&end));
+    assert!(found
+        .path
+        .windows(2)
+        .all(|w| open(&maze, w[0]).contains(&w[1])));
+
+    let found = astar(
+        [start],
+        |p| open(&maze, *p).into_iter().map(|n| (n, 1)),
+        |p| p.0.abs_diff(end.0) + p.1.abs_diff(end.1),
+        |p| *p == end,
+    )
+    .unwrap();
+    assert_eq!(found.cost, 14);
+}
+
+#[test]
+fn dijkstra_prefers_cheap_edges() {
+    // a -> d directly costs 10, going through b and c costs 3
+    let edges = |n: &char| match n {
+        'a' => vec![('d', 10), ('b', 1)],
+        'b' => vec![('c', 1)],
+        'c' => vec![('d', 1)],
+        _ => vec![],
+    };
+
+    let found = dijkstra(['a'], edges, |n| *n == 'd').unwrap();
+    assert_eq!(found.cost, 3);
+    assert_eq!(found.path, ['a', 'b', 'c', 'd']);
+
+    assert_eq!(
+        bfs(['a'], |n| edges(n).into_iter().map(|e| e.0), |n| *n == 'd')
+            .unwrap()
+            .path,
+        ['a', 'd']
+    );
+}
+
+#[test]
+fn multiple_starts_and_unreachable() {
+    let next = |n: &i32| if *n < 10 { vec![*n + 1] } else { vec![] };
+
+    let found = bfs([0, 7, 3], next, |n| *n == 10).unwrap();
+    assert_eq!(found.cost, 3);
+    assert_eq!(found.path, [7, 8, 9, 10]);
+
+    assert_eq!(bfs([0], next, |n| *n == 11), None);
+    assert_eq!(
+        dijkstra([0], |n| next(n).into_iter().map(|m| (m, 1)), |n| *n < 0),
+        None
+    );
+}
+
+#[test]
+fn astar_with_inconsistent_heuristic() {
+    // c is first reached through a for 6, then through b for 5. The heuristic
+    // never overestimates but drops by more than the b -> c edge costs.
+    let edges = |n: &char| match n {
+        's' => vec![('a', 2), ('b', 4)],
+        'a' => vec![('c', 4)],
+        'b' => vec![('c', 1)],
+        'c' => vec![('g', 10)],
+        _ => vec![],
+    };
+    let heuristic = |n: &char| if *n == 'b' { 10 } else { 0 };
+
+    let found = astar(['s'], edges, heuristic, |n| *n == 'g').unwrap();
+    assert_eq!(found.cost, 15);
+    assert_eq!(found.path, ['s', 'b', 'c', 'g']);
+}
@@ -0,0 +1,47 @@
+use aoc2022::{
+    days,
+    verify::{KnownAnswers, Verdict},
+    Answer,
+};
+
+#[test]
+fn answers_file_covers_every_day() {
+    let known = KnownAnswers::parse(include_str!("../answers.toml")).unwrap();
+    for day in days::DAYS {
+        assert!(known.get(day.number, 1).is_some(), "day {}", day.number);
+        assert!(known.get(day.number, 2).is_some(), "day {}", day.number);
+    }
+}
+
+#[test]
+fn verdicts() {
+    let known = KnownAnswers::parse(
+        "[day01]\npart1 = 1_000\n\n[day05]\npart1 = \"CMZ\"\npart2 = \"\"\"\n#.\n.#\"\"\"\n",
+    )
+    .unwrap();
+
+    assert_eq!(known.check(1, 1, &Answer::Number(1000)), Verdict::Pass);
+    assert_eq!(
+        known.check(1, 1, &Answer::Number(999)),
+        Verdict::Fail {
+            expected: Answer::Number(1000)
+        }
+    );
+    assert_eq!(known.check(1, 2, &Answer::Number(1000)), Verdict::Unknown);
+    assert_eq!(
+        known.check(5, 1, &Answer::Text("CMZ".into())),
+        Verdict::Pass
+    );
+    assert_eq!(
+        known.check(5, 2, &Answer::Text("#.\n.#".into())),
+        Verdict::Pass
+    );
+}
+
+#[test]
+fn invalid_answers_file() {
+    assert!(KnownAnswers::parse("part1 = 3").is_err());
+    assert!(KnownAnswers::parse("[day01]\npart3 = 3").is_err());
+    assert!(KnownAnswers::parse("[day01]\npart1 = abc").is_err());
+    assert!(KnownAnswers::parse("[day01]\npart1 = 1\npart1 = 2").is_err());
+}